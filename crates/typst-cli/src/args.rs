@@ -1,5 +1,7 @@
 use std::fmt::{self, Display, Formatter};
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use semver::Version;
 
@@ -64,9 +66,45 @@ pub struct CompileCommand {
     #[arg(long = "ppi", default_value_t = 144.0)]
     pub ppi: f32,
 
+    /// Scales each page to fit onto the given paper size, e.g. `a4`
+    #[arg(long = "fit", value_name = "PAPER")]
+    pub fit: Option<String>,
+
+    /// Arranges the pages on sheets in a grid of the given number of columns
+    /// and rows, e.g. `2x1`. The sheets have the size of the `--fit` paper or
+    /// else of the first page
+    #[arg(long = "n-up", value_name = "COLSxROWS")]
+    pub n_up: Option<NUp>,
+
     /// Produces a flamegraph of the compilation process
     #[arg(long = "flamegraph", value_name = "OUTPUT_SVG")]
     pub flamegraph: Option<Option<PathBuf>>,
+
+    /// Runs the layout without exporting, e.g. to profile the layout alone
+    #[arg(long = "layout-only", conflicts_with = "open")]
+    pub layout_only: bool,
+}
+
+/// A grid of pages per sheet.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct NUp {
+    /// The number of pages next to each other.
+    pub cols: NonZeroUsize,
+    /// The number of pages below each other.
+    pub rows: NonZeroUsize,
+}
+
+impl FromStr for NUp {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = "expected a grid of columns and rows like `2x1`";
+        let (cols, rows) = s.split_once('x').ok_or(error)?;
+        Ok(Self {
+            cols: cols.parse().map_err(|_| error)?,
+            rows: rows.parse().map_err(|_| error)?,
+        })
+    }
 }
 
 /// Processes an input file to extract provided metadata
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -5,11 +6,12 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 use codespan_reporting::term::{self, termcolor};
 use termcolor::{ColorChoice, StandardStream};
 use typst::diag::{bail, Severity, SourceDiagnostic, StrResult};
-use typst::doc::Document;
+use typst::doc::{Document, Frame};
 use typst::eval::{eco_format, Tracer};
-use typst::geom::Color;
+use typst::geom::{Axes, Color, Size};
 use typst::syntax::{FileId, Source, Span};
 use typst::{World, WorldExt};
+use typst_library::layout::Paper;
 
 use crate::args::{CompileCommand, DiagnosticFormat, OutputFormat};
 use crate::watch::Status;
@@ -74,13 +76,20 @@ pub fn compile_once(
     world.source(world.main()).map_err(|err| err.to_string())?;
 
     let mut tracer = Tracer::new();
-    let result = typst::compile(world, &mut tracer);
+    let result = if command.layout_only {
+        typst::layout(world, &mut tracer).map(|_| None)
+    } else {
+        typst::compile(world, &mut tracer).map(Some)
+    };
     let warnings = tracer.warnings();
 
     match result {
-        // Export the PDF / PNG.
+        // Export the PDF / PNG, unless only the layout is of interest.
         Ok(document) => {
-            export(&document, command)?;
+            if let Some(document) = document {
+                export(&document, command)?;
+            }
+
             let duration = start.elapsed();
 
             tracing::info!("Compilation succeeded in {duration:?}");
@@ -124,6 +133,7 @@ pub fn compile_once(
 
 /// Export into the target format.
 fn export(document: &Document, command: &CompileCommand) -> StrResult<()> {
+    let document = &arrange(document, command)?;
     match command.output_format()? {
         OutputFormat::Png => export_image(document, command, ImageExportFormat::Png),
         OutputFormat::Svg => export_image(document, command, ImageExportFormat::Svg),
@@ -131,6 +141,42 @@ fn export(document: &Document, command: &CompileCommand) -> StrResult<()> {
     }
 }
 
+/// Arrange the pages for export as requested by `--fit` and `--n-up`.
+fn arrange<'a>(
+    document: &'a Document,
+    command: &CompileCommand,
+) -> StrResult<Cow<'a, Document>> {
+    let paper = match &command.fit {
+        Some(name) => {
+            let paper: Paper =
+                name.parse().map_err(|err| eco_format!("{err}: {name}"))?;
+            Some(Size::new(paper.width(), paper.height()))
+        }
+        None => None,
+    };
+
+    if let Some(grid) = command.n_up {
+        let first = document.pages.first().map(Frame::size);
+        let Some(sheet) = paper.or(first) else {
+            return Ok(Cow::Borrowed(document));
+        };
+
+        let grid = Axes::new(grid.cols.get(), grid.rows.get());
+        return Ok(Cow::Owned(document.n_up(sheet, grid)));
+    }
+
+    let Some(size) = paper else {
+        return Ok(Cow::Borrowed(document));
+    };
+
+    let mut document = document.clone();
+    for page in &mut document.pages {
+        page.fit(size);
+    }
+
+    Ok(Cow::Owned(document))
+}
+
 /// Export to a PDF.
 fn export_pdf(document: &Document, command: &CompileCommand) -> StrResult<()> {
     let output = command.output();
@@ -317,3 +363,154 @@ impl<'a> codespan_reporting::files::Files<'a> for SystemWorld {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use clap::Parser;
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use super::*;
+    use crate::args::{CliArguments, Command, NUp};
+
+    fn compile_command(dir: &Path, text: &str, extra: &[&str]) -> CompileCommand {
+        let fonts = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/fonts");
+        let input = dir.join("main.typ");
+        fs::write(&input, text).unwrap();
+
+        let mut args = vec!["typst".into(), "compile".into(), input.into_os_string()];
+        args.extend(["--font-path".into(), fonts.into_os_string()]);
+        args.extend(extra.iter().map(|&arg| arg.into()));
+        match CliArguments::parse_from(args).command {
+            Command::Compile(command) => command,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Counts the outline glyphs that are rasterized.
+    #[derive(Default, Clone)]
+    struct Glyphs(Arc<AtomicUsize>);
+
+    impl Subscriber for Glyphs {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes) -> Id {
+            if span.metadata().name() == "render_outline_glyph" {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event) {}
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    /// Run `f`, returning its result and the number of rasterized glyphs.
+    fn rasterized<T>(f: impl FnOnce() -> T) -> (T, usize) {
+        let glyphs = Glyphs::default();
+        let output = tracing::subscriber::with_default(glyphs.clone(), f);
+        (output, glyphs.0.load(Ordering::Relaxed))
+    }
+
+    #[test]
+    fn test_layout_only_skips_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("main.png");
+
+        let mut command = compile_command(dir.path(), "Hello", &["--layout-only"]);
+        command.output = Some(output.clone());
+        let (result, glyphs) = rasterized(|| compile(command));
+        result.unwrap();
+        assert_eq!(glyphs, 0);
+        assert!(!output.exists());
+
+        let mut command = compile_command(dir.path(), "Hello", &[]);
+        command.output = Some(output.clone());
+        let (result, glyphs) = rasterized(|| compile(command));
+        result.unwrap();
+        assert!(glyphs > 0);
+        assert!(output.exists());
+    }
+
+    #[test]
+    fn test_layout_returns_frames() {
+        let dir = tempfile::tempdir().unwrap();
+        let command = compile_command(dir.path(), "A #pagebreak() B", &[]);
+        let world = SystemWorld::new(&command.common).unwrap();
+
+        let mut tracer = Tracer::new();
+        let (frames, glyphs) = rasterized(|| typst::layout(&world, &mut tracer));
+        let frames = frames.unwrap();
+        assert_eq!(frames.len(), 2);
+        assert!(frames.iter().all(|frame| frame.items().next().is_some()));
+        assert_eq!(glyphs, 0);
+    }
+
+    /// The pixel size of a PNG file.
+    fn png_size(path: &Path) -> (u32, u32) {
+        let data = fs::read(path).unwrap();
+        let int = |i: usize| u32::from_be_bytes(data[i..i + 4].try_into().unwrap());
+        (int(16), int(20))
+    }
+
+    #[test]
+    fn test_fit_scales_pages_to_paper() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("main.png");
+
+        let text = "#set page(width: 100pt, height: 50pt)\nHello";
+        let mut command = compile_command(dir.path(), text, &["--fit", "a5"]);
+        command.output = Some(output.clone());
+        command.ppi = 72.0;
+        compile(command).unwrap();
+
+        let width = Paper::A5.width().to_pt().round() as u32;
+        let height = Paper::A5.height().to_pt().round() as u32;
+        assert_eq!(png_size(&output), (width, height));
+    }
+
+    #[test]
+    fn test_n_up_arranges_pages_on_sheets() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("sheet-{n}.png");
+
+        let text = "#set page(width: 100pt, height: 100pt)\n\
+                    A #pagebreak() B #pagebreak() C";
+        let mut command = compile_command(dir.path(), text, &["--n-up", "2x1"]);
+        command.output = Some(output);
+        command.ppi = 72.0;
+        compile(command).unwrap();
+
+        // Three pages two-up give two sheets of the first page's size.
+        let sheet = |n: usize| dir.path().join(format!("sheet-{n}.png"));
+        assert_eq!(png_size(&sheet(1)), (100, 100));
+        assert_eq!(png_size(&sheet(2)), (100, 100));
+        assert!(!sheet(3).exists());
+    }
+
+    #[test]
+    fn test_n_up_rejects_malformed_grid() {
+        assert!("2x1".parse::<NUp>().is_ok());
+        assert!("2".parse::<NUp>().is_err());
+        assert!("0x1".parse::<NUp>().is_err());
+    }
+
+    #[test]
+    fn test_fit_rejects_unknown_paper() {
+        let dir = tempfile::tempdir().unwrap();
+        let command = compile_command(dir.path(), "Hello", &["--fit", "napkin"]);
+        assert_eq!(compile(command).unwrap_err(), "unknown paper size: napkin");
+    }
+}
@@ -181,3 +181,35 @@ impl FontSearcher {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_dir_makes_fonts_selectable() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/fonts");
+        let variant = FontVariant::default();
+        let mut searcher = FontSearcher::new();
+        assert!(searcher.book.select("linux libertine", variant).is_none());
+
+        searcher.search_dir(dir);
+        let index = searcher.book.select("linux libertine", variant).unwrap();
+
+        let font = searcher.fonts[index].get().unwrap();
+        assert_eq!(font.info().family, "Linux Libertine");
+    }
+
+    #[test]
+    #[cfg(feature = "embed-fonts")]
+    fn test_embedded_fonts_without_configuration() {
+        let mut searcher = FontSearcher::new();
+        searcher.add_embedded();
+
+        let variant = FontVariant::default();
+        for family in ["linux libertine", "new computer modern math", "dejavu sans mono"] {
+            let index = searcher.book.select(family, variant).unwrap();
+            assert!(searcher.fonts[index].get().is_some());
+        }
+    }
+}
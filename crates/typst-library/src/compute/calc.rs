@@ -2,6 +2,7 @@
 
 use std::cmp;
 use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::ops::{Div, Rem};
 
 use typst::eval::{Module, Scope};
@@ -1000,6 +1001,15 @@ impl Num {
     }
 }
 
+impl Hash for Num {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match *self {
+            Self::Int(v) => v.hash(state),
+            Self::Float(v) => v.to_bits().hash(state),
+        }
+    }
+}
+
 cast! {
     Num,
     self => match self {
@@ -4,10 +4,10 @@ use crate::text::TextElem;
 /// Separates a region into multiple equally sized columns.
 ///
 /// The `column` function allows to separate the interior of any container into
-/// multiple columns. It will not equalize the height of the columns, instead,
-/// the columns will take up the height of their container or the remaining
-/// height on the page. The columns function can break across pages if
-/// necessary.
+/// multiple columns. By default, it will not equalize the height of the
+/// columns, instead, the columns will take up the height of their container or
+/// the remaining height on the page. The columns function can break across
+/// pages if necessary.
 ///
 /// ## Example { #example }
 /// ```example
@@ -44,6 +44,18 @@ pub struct ColumnsElem {
     #[default(Ratio::new(0.04).into())]
     pub gutter: Rel<Length>,
 
+    /// Whether to distribute the content of the last region evenly across the
+    /// columns instead of filling each column before moving on to the next.
+    ///
+    /// ```example
+    /// #box(height: 68pt, columns(2, balance: true)[
+    ///   This research was funded by the
+    ///   National Academy of Sciences.
+    /// ])
+    /// ```
+    #[default(false)]
+    pub balance: bool,
+
     /// The content that should be layouted into the columns.
     #[required]
     pub body: Content,
@@ -70,28 +82,68 @@ impl Layout for ColumnsElem {
         let gutter = self.gutter(styles).relative_to(regions.base().x);
         let width = (regions.size.x - gutter * (columns - 1) as f64) / columns as f64;
 
-        let backlog: Vec<_> = std::iter::once(&regions.size.y)
+        // The height of each column, flattened across regions.
+        let mut heights: Vec<_> = std::iter::once(&regions.size.y)
             .chain(regions.backlog)
             .flat_map(|&height| std::iter::repeat(height).take(columns))
-            .skip(1)
             .collect();
 
-        // Create the pod regions.
-        let pod = Regions {
-            size: Size::new(width, regions.size.y),
-            full: regions.full,
-            backlog: &backlog,
-            last: regions.last,
-            expand: Axes::new(true, regions.expand.y),
-            root: regions.root,
+        // Layout the children. When balancing, this is only a first pass to
+        // find out how many regions the content needs.
+        let balance = self.balance(styles);
+        let mut fragment = if balance {
+            body.measure(vt, styles, pod(&regions, width, &heights))?
+        } else {
+            body.layout(vt, styles, pod(&regions, width, &heights))?
         };
 
-        // Layout the children.
-        let mut frames = body.layout(vt, styles, pod)?.into_iter();
+        let total_regions = (fragment.len() as f32 / columns as f32).ceil() as usize;
+
+        // Balance the last region by searching for the smallest column height
+        // at which the content still fits into the same number of regions.
+        if balance {
+            if total_regions > 0 {
+                let start = (total_regions - 1) * columns;
+
+                // In an infinite region, all content ends up in the first
+                // column, so its measured height is an upper bound.
+                let mut last = regions.iter().nth(total_regions - 1).unwrap().y;
+                if !last.is_finite() {
+                    last = fragment
+                        .iter()
+                        .skip(start)
+                        .map(Frame::height)
+                        .fold(Abs::zero(), Abs::max);
+                }
+
+                heights = regions
+                    .iter()
+                    .take(total_regions)
+                    .flat_map(|size| std::iter::repeat(size.y).take(columns))
+                    .collect();
+
+                let (mut lo, mut hi) = (Abs::zero(), last);
+                while hi - lo > BALANCE_TOLERANCE {
+                    let mid = (lo + hi) / 2.0;
+                    heights[start..].fill(mid);
+                    let trial = pod(&regions, width, &heights);
+                    if body.measure(vt, styles, trial)?.len() <= total_regions * columns {
+                        hi = mid;
+                    } else {
+                        lo = mid;
+                    }
+                }
+
+                heights[start..].fill(hi);
+            }
+
+            fragment = body.layout(vt, styles, pod(&regions, width, &heights))?;
+        }
+
+        let mut frames = fragment.into_iter();
         let mut finished = vec![];
 
         let dir = TextElem::dir_in(styles);
-        let total_regions = (frames.len() as f32 / columns as f32).ceil() as usize;
 
         // Stitch together the columns for each region.
         for region in regions.iter().take(total_regions) {
@@ -127,6 +179,21 @@ impl Layout for ColumnsElem {
     }
 }
 
+/// Create the pod regions for columns with the given heights.
+fn pod<'a>(regions: &Regions, width: Abs, heights: &'a [Abs]) -> Regions<'a> {
+    Regions {
+        size: Size::new(width, heights[0]),
+        full: regions.full,
+        backlog: &heights[1..],
+        last: regions.last,
+        expand: Axes::new(true, regions.expand.y),
+        root: regions.root,
+    }
+}
+
+/// How close the balanced column height gets to the smallest possible one.
+const BALANCE_TOLERANCE: Abs = Abs::raw(0.5);
+
 /// Forces a column break.
 ///
 /// The function will behave like a [page break]($func/pagebreak) when used in a
@@ -3,6 +3,7 @@ use typst::eval::AutoValue;
 use super::VElem;
 use crate::layout::Spacing;
 use crate::prelude::*;
+use crate::text::TextElem;
 
 /// An inline-level container that sizes content.
 ///
@@ -91,10 +92,29 @@ pub struct BoxElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
-    /// Whether to clip the content inside the box.
+    /// Whether to clip the content inside the box. If the box has rounded
+    /// corners, the content is clipped to them, too.
     #[default(false)]
     pub clip: bool,
 
+    /// How to handle content that overflows the box.
+    ///
+    /// With `{"hidden"}`, the content is clipped like with
+    /// [`clip`]($func/box.clip) and a warning is emitted if something was
+    /// cut off. With `{"ellipsis"}`, the content is clipped, too, but the
+    /// truncation is marked with an ellipsis at the end of the box.
+    ///
+    /// ```example
+    /// #box(
+    ///   width: 50pt,
+    ///   height: 12pt,
+    ///   overflow: "ellipsis",
+    ///   lorem(10),
+    /// )
+    /// ```
+    #[default(Overflow::Visible)]
+    pub overflow: Overflow,
+
     /// The contents of the box.
     #[positional]
     pub body: Option<Content>,
@@ -144,9 +164,35 @@ impl Layout for BoxElem {
             frame.set_baseline(frame.baseline() - shift);
         }
 
+        // Indicate truncated contents.
+        let overflow = self.overflow(styles);
+        if overflow != Overflow::Visible && overflows(&frame, Point::zero(), frame.size())
+        {
+            match overflow {
+                Overflow::Visible => {}
+                Overflow::Hidden => vt.tracer.warn(
+                    warning!(self.span(), "content overflows the box and is clipped")
+                        .with_hint("use `overflow: \"ellipsis\"` to mark the truncation"),
+                ),
+                Overflow::Ellipsis => {
+                    let pod = Regions::one(frame.size(), Axes::splat(false));
+                    let mark = TextElem::packed('…');
+                    let mark = mark.layout(vt, styles, pod)?.into_frame();
+                    let pos = (frame.size() - mark.size()).to_point();
+                    let end = pos + mark.size().to_point();
+                    frame = truncate(&frame, Point::zero(), pos, end);
+                    frame.push_frame(pos, mark);
+                }
+            }
+        }
+
         // Clip the contents
-        if self.clip(styles) {
-            frame.clip();
+        if self.clip(styles) || overflow != Overflow::Visible {
+            let size = frame.size();
+            let radius = self
+                .radius(styles)
+                .map(|side| side.relative_to(size.x.min(size.y) / 2.0));
+            frame.clip(rounded_rect_path(size, radius));
         }
 
         // Prepare fill and stroke.
@@ -167,6 +213,83 @@ impl Layout for BoxElem {
     }
 }
 
+/// How to handle content that overflows a box.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum Overflow {
+    /// Show the overflowing content.
+    Visible,
+    /// Clip the overflowing content and warn about it.
+    Hidden,
+    /// Clip the overflowing content and mark it with an ellipsis.
+    Ellipsis,
+}
+
+/// Whether any text or image in the frame extends past the given size.
+///
+/// Text is measured down to its font's descender. Transforms of nested groups
+/// are not taken into account.
+fn overflows(frame: &Frame, offset: Point, size: Size) -> bool {
+    frame.items().any(|(pos, item)| {
+        let pos = offset + *pos;
+        let end = match item {
+            FrameItem::Group(group) => return overflows(&group.frame, pos, size),
+            FrameItem::Text(text) => {
+                let descent = -text.font.metrics().descender.at(text.size);
+                pos + Point::new(text.width(), descent)
+            }
+            FrameItem::Image(_, extent, _) => pos + extent.to_point(),
+            FrameItem::Shape(..) | FrameItem::Meta(..) => return false,
+        };
+        !size.x.fits(end.x) || !size.y.fits(end.y)
+    })
+}
+
+/// Remove the glyphs of all text in the frame that reaches into the area
+/// between `start` and `end`, so that a mark can be placed there.
+///
+/// Like for [`overflows`], transforms of nested groups are not taken into
+/// account.
+fn truncate(frame: &Frame, offset: Point, start: Point, end: Point) -> Frame {
+    let mut output = frame.clone();
+    output.clear();
+
+    for (pos, item) in frame.items() {
+        let abs = offset + *pos;
+        let item = match item {
+            FrameItem::Group(group) => FrameItem::Group(GroupItem {
+                frame: truncate(&group.frame, abs, start, end),
+                ..group.clone()
+            }),
+            FrameItem::Text(text) => {
+                let metrics = text.font.metrics();
+                let top = abs.y - metrics.ascender.at(text.size);
+                let bottom = abs.y - metrics.descender.at(text.size);
+                let mut right = abs.x + text.width();
+                if top >= end.y || bottom <= start.y || right <= start.x {
+                    output.push(*pos, item.clone());
+                    continue;
+                }
+
+                let mut text = text.clone();
+                while right > start.x {
+                    let Some(glyph) = text.glyphs.pop() else { break };
+                    right -= glyph.x_advance.at(text.size);
+                }
+
+                if text.glyphs.is_empty() {
+                    continue;
+                }
+
+                FrameItem::Text(text)
+            }
+            _ => item.clone(),
+        };
+        output.push(*pos, item);
+    }
+
+    output
+}
+
 /// A block-level container.
 ///
 /// Such a container can be used to separate content, size it, and give it a
@@ -316,7 +439,8 @@ pub struct BlockElem {
     #[default(VElem::block_spacing(Em::new(1.2).into()))]
     pub below: VElem,
 
-    /// Whether to clip the content inside the block.
+    /// Whether to clip the content inside the block. If the block has rounded
+    /// corners, the content is clipped to them, too.
     #[default(false)]
     pub clip: bool,
 
@@ -330,6 +454,12 @@ pub struct BlockElem {
     #[internal]
     #[default(false)]
     pub sticky: bool,
+
+    /// The block's role in the document's logical structure, if any.
+    ///
+    /// This is used by tagged PDF export to mark up e.g. headings and figures.
+    #[internal]
+    pub role: Option<Role>,
 }
 
 impl Layout for BlockElem {
@@ -411,8 +541,11 @@ impl Layout for BlockElem {
 
         // Clip the contents
         if self.clip(styles) {
+            let radius = self.radius(styles);
             for frame in frames.iter_mut() {
-                frame.clip();
+                let size = frame.size();
+                let radius = radius.map(|side| side.relative_to(size.x.min(size.y) / 2.0));
+                frame.clip(rounded_rect_path(size, radius));
             }
         }
 
@@ -445,7 +578,12 @@ impl Layout for BlockElem {
             frame.meta(styles, false);
         }
 
-        Ok(Fragment::frames(frames))
+        let mut fragment = Fragment::frames(frames);
+        if let Some(role) = self.role(styles) {
+            fragment.tag(role);
+        }
+
+        Ok(fragment)
     }
 }
 
@@ -495,3 +633,73 @@ cast! {
     v: Rel<Length> => Self::Rel(v),
     v: Fr => Self::Fr(v),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellipsis_does_not_overlap_text() {
+        let document = crate::tests::compile(
+            "#box(width: 30pt, height: 12pt, overflow: \"ellipsis\", lorem(10))",
+        );
+
+        let texts: Vec<_> = crate::tests::items(&document.pages[0])
+            .into_iter()
+            .filter_map(|(pos, item)| match item {
+                FrameItem::Text(text) => Some((pos, text)),
+                _ => None,
+            })
+            .collect();
+
+        let (mark, _) = texts.iter().find(|(_, text)| text.text == "…").unwrap();
+        for (pos, text) in &texts {
+            if text.text != "…" && pos.y <= mark.y {
+                assert!(pos.x + text.width() <= mark.x + Abs::pt(0.01));
+            }
+        }
+    }
+
+    #[test]
+    fn test_box_serializes_with_paint_and_stroke() {
+        let document = crate::tests::compile(
+            "#set page(width: 40pt, height: 30pt, margin: 10pt)\n\
+             #box(width: 20pt, height: 10pt, fill: red, stroke: 2pt + blue)",
+        );
+
+        // Flatten the page so that the shape is at its absolute position.
+        let page = &document.pages[0];
+        let mut frame = Frame::new(page.size());
+        for (pos, item) in crate::tests::items(page) {
+            if matches!(item, FrameItem::Shape(..)) {
+                frame.push(pos, item);
+            }
+        }
+
+        assert_eq!(
+            serde_json::to_value(&frame).unwrap(),
+            serde_json::json!({
+                "width": 40.0,
+                "height": 30.0,
+                "baseline": 30.0,
+                "items": [{
+                    "x": 10.0,
+                    "y": 10.0,
+                    "kind": "shape",
+                    "geometry": "rect",
+                    "width": 20.0,
+                    "height": 10.0,
+                    "fill": { "kind": "color", "color": "rgb(\"#ff4136\")" },
+                    "stroke": {
+                        "paint": { "kind": "color", "color": "rgb(\"#0074d9\")" },
+                        "thickness": 2.0,
+                        "cap": "butt",
+                        "join": "miter",
+                        "dash": null,
+                        "miter-limit": 4.0,
+                    },
+                }],
+            })
+        );
+    }
+}
@@ -251,7 +251,13 @@ impl Layout for EnumElem {
             cells.push(Content::empty());
             cells.push(resolved);
             cells.push(Content::empty());
-            cells.push(item.body().styled(Self::set_parents(Parent(number))));
+            let body = item.body().styled(Self::set_parents(Parent(number)));
+            cells.push(
+                BlockElem::new()
+                    .with_body(Some(body))
+                    .with_role(Some(Role::ListItem))
+                    .pack(),
+            );
             number = number.saturating_add(1);
         }
 
@@ -266,9 +272,11 @@ impl Layout for EnumElem {
             &cells,
             regions,
             styles,
-        );
+        )?;
 
-        Ok(layouter.layout(vt)?.fragment)
+        let mut fragment = layouter.layout(vt)?.fragment;
+        fragment.tag(Role::List);
+        Ok(fragment)
     }
 }
 
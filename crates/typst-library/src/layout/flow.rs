@@ -1,5 +1,8 @@
 use std::mem;
 
+use typst::eval::Tracer;
+use typst::model::DelayedErrors;
+
 use super::{
     AlignElem, BlockElem, ColbreakElem, ColumnsElem, ParElem, PlaceElem, Spacing, VElem,
 };
@@ -32,58 +35,101 @@ impl Layout for FlowElem {
         styles: StyleChain,
         regions: Regions,
     ) -> SourceResult<Fragment> {
-        let mut layouter = FlowLayouter::new(regions, styles);
-
-        for mut child in &self.children() {
-            let outer = styles;
-            let mut styles = styles;
-            if let Some((elem, map)) = child.to_styled() {
-                child = elem;
-                styles = outer.chain(map);
-            }
+        let children = self.children();
+
+        // Flows that aren't laid out into a sequence of same-sized pages are
+        // laid out in one go.
+        let paged = regions.root
+            && regions.backlog.is_empty()
+            && regions.last == Some(regions.size.y)
+            && regions.full == regions.size.y
+            && regions.size.y.is_finite();
+
+        if !paged {
+            let mut layouter = FlowLayouter::new(regions, styles);
+            layouter.layout_children(vt, 0, |i| children.get(i).cloned())?;
+            return layouter.finish(vt);
+        }
 
-            if let Some(elem) = child.to::<VElem>() {
-                layouter.layout_spacing(vt, elem, styles)?;
-            } else if let Some(elem) = child.to::<ParElem>() {
-                layouter.layout_par(vt, elem, styles)?;
-            } else if child.is::<LineElem>()
-                || child.is::<RectElem>()
-                || child.is::<SquareElem>()
-                || child.is::<EllipseElem>()
-                || child.is::<CircleElem>()
-                || child.is::<ImageElem>()
-                || child.is::<PolygonElem>()
-                || child.is::<PathElem>()
-            {
-                let layoutable = child.with::<dyn Layout>().unwrap();
-                layouter.layout_single(vt, layoutable, styles)?;
-            } else if child.is::<MetaElem>() {
-                let mut frame = Frame::new(Size::zero());
-                frame.meta(styles, true);
-                layouter.items.push(FlowItem::Frame {
-                    frame,
-                    aligns: Axes::new(Align::Top, Align::Left),
-                    sticky: true,
-                    movable: false,
-                });
-            } else if let Some(placed) = child.to::<PlaceElem>() {
-                layouter.layout_placed(vt, placed, styles)?;
-            } else if child.can::<dyn Layout>() {
-                layouter.layout_multiple(vt, child, styles)?;
-            } else if child.is::<ColbreakElem>() {
-                if !layouter.regions.backlog.is_empty() || layouter.regions.last.is_some()
-                {
-                    layouter.finish_region(vt)?;
-                }
-            } else {
-                bail!(child.span(), "unexpected flow child");
+        #[allow(clippy::too_many_arguments)]
+        #[comemo::memoize]
+        fn cached(
+            children: Tracked<Children>,
+            start: Checkpoint,
+            world: Tracked<dyn World + '_>,
+            introspector: Tracked<Introspector>,
+            locator: Tracked<Locator>,
+            delayed: TrackedMut<DelayedErrors>,
+            tracer: TrackedMut<Tracer>,
+            styles: StyleChain,
+            regions: Regions,
+        ) -> SourceResult<(Vec<Frame>, Option<Checkpoint>)> {
+            let mut locator = Locator::chained(locator);
+            let mut vt = Vt {
+                world,
+                introspector,
+                locator: &mut locator,
+                delayed,
+                tracer,
+            };
+            FlowLayouter::new(regions, styles).layout_pages(&mut vt, children, start)
+        }
+
+        // Lay out the pages in chunks that start at a clean page break, so
+        // that the chunks before an edit are taken from the cache. A change
+        // that shifts the pagination moves the start of the following chunks
+        // and thus lays them out again.
+        let mut frames = vec![];
+        let mut start = Checkpoint { child: 0, last_was_par: false };
+        loop {
+            let (chunk, next) = cached(
+                Children(&children).track(),
+                start,
+                vt.world,
+                vt.introspector,
+                vt.locator.track(),
+                TrackedMut::reborrow_mut(&mut vt.delayed),
+                TrackedMut::reborrow_mut(&mut vt.tracer),
+                styles,
+                regions,
+            )?;
+
+            vt.locator.visit_frames(&chunk);
+            frames.extend(chunk);
+
+            match next {
+                Some(next) => start = next,
+                None => break,
             }
         }
 
-        layouter.finish(vt)
+        Ok(Fragment::frames(frames))
+    }
+}
+
+/// The children of a flow.
+///
+/// Tracked so that a chunk of pages only depends on the children that were
+/// laid out into it.
+struct Children<'a>(&'a [Content]);
+
+#[comemo::track]
+impl<'a> Children<'a> {
+    /// The child at the given index, if any.
+    fn get(&self, index: usize) -> Option<Content> {
+        self.0.get(index).cloned()
     }
 }
 
+/// A page break before a child after which flow layout can start afresh.
+#[derive(Debug, Copy, Clone, Hash)]
+struct Checkpoint {
+    /// The index of the first child on the new page.
+    child: usize,
+    /// Whether the child before was a paragraph.
+    last_was_par: bool,
+}
+
 /// Performs flow layout.
 struct FlowLayouter<'a> {
     /// Whether this is the root flow.
@@ -109,6 +155,13 @@ struct FlowLayouter<'a> {
     footnote_config: FootnoteConfig,
     /// Finished frames for previous regions.
     finished: Vec<Frame>,
+    /// The index of the child that is being laid out.
+    child: usize,
+    /// Whether to stop at the first clean page break.
+    paged: bool,
+    /// Where the next chunk of pages starts, once a clean page break was
+    /// found.
+    checkpoint: Option<Checkpoint>,
 }
 
 /// Cached footnote configuration.
@@ -137,6 +190,8 @@ enum FlowItem {
         delta: Axes<Rel<Abs>>,
         float: bool,
         clearance: Abs,
+        /// Where to report overlaps with flowed content, if at all.
+        lint: Option<Span>,
     },
     /// A footnote frame (can also be the separator).
     Footnote(Frame),
@@ -178,7 +233,94 @@ impl<'a> FlowLayouter<'a> {
                 gap: FootnoteEntry::gap_in(styles),
             },
             finished: vec![],
+            child: 0,
+            paged: false,
+            checkpoint: None,
+        }
+    }
+
+    /// Layout a chunk of pages, starting at the given checkpoint and stopping
+    /// at the first clean page break.
+    #[tracing::instrument(name = "FlowLayouter::layout_pages", skip_all)]
+    fn layout_pages(
+        mut self,
+        vt: &mut Vt,
+        children: Tracked<Children>,
+        start: Checkpoint,
+    ) -> SourceResult<(Vec<Frame>, Option<Checkpoint>)> {
+        self.paged = true;
+        self.last_was_par = start.last_was_par;
+        self.layout_children(vt, start.child, |i| children.get(i))?;
+        match self.checkpoint {
+            Some(next) => Ok((self.finished, Some(next))),
+            None => Ok((self.finish(vt)?.into_frames(), None)),
+        }
+    }
+
+    /// Layout the children from the given index on.
+    fn layout_children(
+        &mut self,
+        vt: &mut Vt,
+        start: usize,
+        children: impl Fn(usize) -> Option<Content>,
+    ) -> SourceResult<()> {
+        for i in start.. {
+            let Some(child) = children(i) else { break };
+            self.child = i;
+            self.layout_child(vt, &child)?;
+            if self.checkpoint.is_some() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Layout a single child.
+    fn layout_child(&mut self, vt: &mut Vt, mut child: &Content) -> SourceResult<()> {
+        let outer = self.styles;
+        let mut styles = outer;
+        if let Some((elem, map)) = child.to_styled() {
+            child = elem;
+            styles = outer.chain(map);
         }
+
+        if let Some(elem) = child.to::<VElem>() {
+            self.layout_spacing(vt, elem, styles)?;
+        } else if let Some(elem) = child.to::<ParElem>() {
+            self.layout_par(vt, elem, styles)?;
+        } else if child.is::<LineElem>()
+            || child.is::<RectElem>()
+            || child.is::<SquareElem>()
+            || child.is::<EllipseElem>()
+            || child.is::<CircleElem>()
+            || child.is::<ImageElem>()
+            || child.is::<PolygonElem>()
+            || child.is::<PathElem>()
+        {
+            let layoutable = child.with::<dyn Layout>().unwrap();
+            self.layout_single(vt, layoutable, styles)?;
+        } else if child.is::<MetaElem>() {
+            let mut frame = Frame::new(Size::zero());
+            frame.meta(styles, true);
+            self.items.push(FlowItem::Frame {
+                frame,
+                aligns: Axes::new(Align::Top, Align::Left),
+                sticky: true,
+                movable: false,
+            });
+        } else if let Some(placed) = child.to::<PlaceElem>() {
+            self.layout_placed(vt, placed, styles)?;
+        } else if child.can::<dyn Layout>() {
+            self.layout_multiple(vt, child, styles)?;
+        } else if child.is::<ColbreakElem>() {
+            if !self.regions.backlog.is_empty() || self.regions.last.is_some() {
+                self.finish_region(vt)?;
+            }
+        } else {
+            bail!(child.span(), "unexpected flow child");
+        }
+
+        Ok(())
     }
 
     /// Layout vertical spacing.
@@ -193,7 +335,11 @@ impl<'a> FlowLayouter<'a> {
             vt,
             match v.amount() {
                 Spacing::Rel(rel) => FlowItem::Absolute(
-                    rel.resolve(styles).relative_to(self.initial.y),
+                    rel.resolve(styles).relative_to(if v.remaining(styles) {
+                        self.regions.size.y
+                    } else {
+                        self.initial.y
+                    }),
                     v.weakness(styles) > 0,
                 ),
                 Spacing::Fr(fr) => FlowItem::Fractional(fr),
@@ -228,9 +374,15 @@ impl<'a> FlowLayouter<'a> {
         if let Some(first) = lines.first() {
             if !self.regions.size.y.fits(first.height()) && !self.regions.in_last() {
                 let carry: Vec<_> = self.items.drain(sticky..).collect();
-                self.finish_region(vt)?;
-                for item in carry {
-                    self.layout_item(vt, item)?;
+                if carry.is_empty() {
+                    if self.break_before_child(vt)? {
+                        return Ok(());
+                    }
+                } else {
+                    self.finish_region(vt)?;
+                    for item in carry {
+                        self.layout_item(vt, item)?;
+                    }
                 }
             }
         }
@@ -262,6 +414,14 @@ impl<'a> FlowLayouter<'a> {
         let sticky = BlockElem::sticky_in(styles);
         let pod = Regions::one(self.regions.base(), Axes::splat(false));
         let frame = content.layout(vt, styles, pod)?.into_frame();
+        if self.paged
+            && !self.regions.size.y.fits(frame.height())
+            && !self.regions.in_last()
+            && self.break_before_child(vt)?
+        {
+            return Ok(());
+        }
+
         self.layout_item(vt, FlowItem::Frame { frame, aligns, sticky, movable: true })?;
         self.last_was_par = false;
         Ok(())
@@ -282,8 +442,10 @@ impl<'a> FlowLayouter<'a> {
             aligns.x.unwrap_or(GenAlign::Start).resolve(styles)
         });
         let y_align = alignment.map(|align| align.y.resolve(styles));
+        let lint = placed.lint(styles).then(|| placed.span());
         let frame = placed.layout(vt, styles, self.regions)?.into_frame();
-        let item = FlowItem::Placed { frame, x_align, y_align, delta, float, clearance };
+        let item =
+            FlowItem::Placed { frame, x_align, y_align, delta, float, clearance, lint };
         self.layout_item(vt, item)
     }
 
@@ -305,7 +467,9 @@ impl<'a> FlowLayouter<'a> {
 
         if self.regions.is_full() {
             // Skip directly if region is already full.
-            self.finish_region(vt)?;
+            if self.break_before_child(vt)? {
+                return Ok(());
+            }
         }
 
         // How to align the block.
@@ -492,6 +656,14 @@ impl<'a> FlowLayouter<'a> {
         let mut float_bottom_offset = Abs::zero();
         let mut footnote_offset = Abs::zero();
 
+        // Remember where flowed and linted placed content ends up to detect
+        // overlaps between them.
+        let lint = self.items.iter().any(|item| {
+            matches!(item, FlowItem::Placed { float: false, lint: Some(_), .. })
+        });
+        let mut flowed = vec![];
+        let mut linted = vec![];
+
         // Place all frames.
         for item in self.items.drain(..) {
             match item {
@@ -508,9 +680,20 @@ impl<'a> FlowLayouter<'a> {
                     let y = offset + ruler.position(size.y - used.y);
                     let pos = Point::new(x, y);
                     offset += frame.height();
+                    if let Some(span) = lint.then(|| first_span(&frame)).flatten() {
+                        flowed.push((pos, frame.size(), span));
+                    }
                     output.push_frame(pos, frame);
                 }
-                FlowItem::Placed { frame, x_align, y_align, delta, float, .. } => {
+                FlowItem::Placed {
+                    frame,
+                    x_align,
+                    y_align,
+                    delta,
+                    float,
+                    lint,
+                    ..
+                } => {
                     let x = x_align.position(size.x - frame.width());
                     let y = if float {
                         match y_align {
@@ -539,6 +722,12 @@ impl<'a> FlowLayouter<'a> {
                     let pos = Point::new(x, y)
                         + delta.zip(size).map(|(d, s)| d.relative_to(s)).to_point();
 
+                    if let (false, Some(span)) = (float, lint) {
+                        if !frame.is_empty() {
+                            linted.push((pos, frame.size(), span));
+                        }
+                    }
+
                     output.push_frame(pos, frame);
                 }
                 FlowItem::Footnote(frame) => {
@@ -549,6 +738,24 @@ impl<'a> FlowLayouter<'a> {
             }
         }
 
+        // Report placed content that overlaps flowed content.
+        for &(pos, size, span) in &linted {
+            let hit = flowed.iter().find(|&&(other, other_size, _)| {
+                pos.x < other.x + other_size.x
+                    && other.x < pos.x + size.x
+                    && pos.y < other.y + other_size.y
+                    && other.y < pos.y + size.y
+            });
+
+            if let Some(&(_, _, other)) = hit {
+                vt.tracer.warn(
+                    warning!(span, "placed content overlaps flowed content")
+                        .with_hint("set `lint: false` if the overlap is intended"),
+                );
+                vt.tracer.warn(warning!(other, "this content is overlapped"));
+            }
+        }
+
         // Advance to the next region.
         self.finished.push(output);
         self.regions.next();
@@ -563,6 +770,20 @@ impl<'a> FlowLayouter<'a> {
         Ok(())
     }
 
+    /// Finish the current region before the current child.
+    ///
+    /// Returns whether the layout of this chunk of pages stops here because
+    /// the next chunk can start with the child on a fresh page.
+    fn break_before_child(&mut self, vt: &mut Vt) -> SourceResult<bool> {
+        let clean = self.pending_floats.is_empty();
+        self.finish_region(vt)?;
+        if self.paged && clean {
+            self.checkpoint =
+                Some(Checkpoint { child: self.child, last_was_par: self.last_was_par });
+        }
+        Ok(self.checkpoint.is_some())
+    }
+
     /// Finish layouting and return the resulting fragment.
     fn finish(mut self, vt: &mut Vt) -> SourceResult<Fragment> {
         if self.expand.y {
@@ -618,10 +839,11 @@ impl FlowLayouter<'_> {
             }
 
             self.regions.size.y -= self.footnote_config.gap;
-            let frames = FootnoteEntry::new(notes[k].clone())
+            let mut fragment = FootnoteEntry::new(notes[k].clone())
                 .pack()
-                .layout(vt, self.styles, self.regions.with_root(false))?
-                .into_frames();
+                .layout(vt, self.styles, self.regions.with_root(false))?;
+            fragment.tag(Role::Note);
+            let frames = fragment.into_frames();
 
             // If the entries didn't fit, abort (to keep footnote and entry
             // together).
@@ -676,6 +898,7 @@ impl FlowLayouter<'_> {
         let mut frame = separator.layout(vt, self.styles, pod)?.into_frame();
         frame.size_mut().y += self.footnote_config.clearance;
         frame.translate(Point::with_y(self.footnote_config.clearance));
+        frame.tag(Role::Artifact);
 
         self.has_footnotes = true;
         self.regions.size.y -= frame.height();
@@ -701,3 +924,16 @@ fn find_footnotes(notes: &mut Vec<FootnoteElem>, frame: &Frame) {
         }
     }
 }
+
+/// The span of the first spanned item in the frame, if any.
+fn first_span(frame: &Frame) -> Option<Span> {
+    frame.items().find_map(|(_, item)| {
+        let span = match item {
+            FrameItem::Group(group) => return first_span(&group.frame),
+            FrameItem::Text(text) => text.glyphs.first()?.span.0,
+            FrameItem::Shape(_, span) | FrameItem::Image(_, _, span) => *span,
+            FrameItem::Meta(..) => return None,
+        };
+        (!span.is_detached()).then_some(span)
+    })
+}
@@ -48,6 +48,22 @@ impl Fragment {
     pub fn iter_mut(&mut self) -> std::slice::IterMut<Frame> {
         self.0.iter_mut()
     }
+
+    /// Mark the frames as one piece of content with a role in the document's
+    /// logical structure, which starts in the first and ends in the last
+    /// non-empty frame.
+    pub fn tag(&mut self, role: Role) {
+        let mut frames = self.0.iter_mut().filter(|frame| !frame.is_empty());
+        let Some(first) = frames.next() else { return };
+        let Some(last) = frames.last() else {
+            first.tag(role);
+            return;
+        };
+
+        let start = FrameItem::Meta(Meta::Tag(Tag::Start(role)), Size::zero());
+        first.prepend(Point::zero(), start);
+        last.push(Point::zero(), FrameItem::Meta(Meta::Tag(Tag::End), Size::zero()));
+    }
 }
 
 impl Debug for Fragment {
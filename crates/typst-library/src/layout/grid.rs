@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use crate::text::TextElem;
 
-use super::Sizing;
+use super::{AlignElem, Sizing};
 
 /// Arranges content in a grid.
 ///
@@ -62,6 +62,10 @@ use super::Sizing;
 /// Display: Grid
 /// Category: layout
 #[element(Layout)]
+#[scope(
+    scope.define("cell", GridCell::func());
+    scope
+)]
 pub struct GridElem {
     /// The column sizes.
     ///
@@ -96,7 +100,8 @@ pub struct GridElem {
 
     /// The contents of the grid cells.
     ///
-    /// The cells are populated in row-major order.
+    /// The cells are populated in row-major order. To let a cell span multiple
+    /// columns or rows, wrap it in a [`grid.cell`]($func/grid.cell).
     #[variadic]
     pub children: Vec<Content>,
 }
@@ -111,19 +116,81 @@ impl Layout for GridElem {
     ) -> SourceResult<Fragment> {
         // Prepare grid layout by unifying content and gutter tracks.
         let cells = self.children();
-        let layouter = GridLayouter::new(
+        let mut layouter = GridLayouter::new(
             Axes::new(&self.columns(styles).0, &self.rows(styles).0),
             Axes::new(&self.column_gutter(styles).0, &self.row_gutter(styles).0),
             &cells,
             regions,
             styles,
-        );
+        )?;
+
+        // Apply the insets and alignments of individual cells.
+        layouter.map_cells(|cell| {
+            cell.finish(Rel::zero(), Smart::Auto);
+            Ok(())
+        })?;
 
         // Measure the columns and layout the grid row-by-row.
         Ok(layouter.layout(vt)?.fragment)
     }
 }
 
+/// A cell in a grid or table that can span multiple columns or rows.
+///
+/// Cells which are not wrapped in this function span exactly one column and
+/// one row. Cells are placed in row-major order, skipping over positions that
+/// are already occupied by a spanning cell from a previous row.
+///
+/// ## Example { #example }
+/// ```example
+/// #table(
+///   columns: 3,
+///   table.cell(colspan: 2)[*Wide*], [A],
+///   table.cell(rowspan: 2)[*Tall*], [B], [C],
+///   [D], [E],
+/// )
+/// ```
+///
+/// Display: Grid Cell
+/// Category: layout
+#[element(Show)]
+pub struct GridCell {
+    /// The cell's body.
+    #[required]
+    pub body: Content,
+
+    /// The number of columns the cell spans.
+    #[default(NonZeroUsize::new(1).unwrap())]
+    pub colspan: NonZeroUsize,
+
+    /// The number of rows the cell spans.
+    #[default(NonZeroUsize::new(1).unwrap())]
+    pub rowspan: NonZeroUsize,
+
+    /// How to align the cell's content. If set to `{auto}`, the alignment of
+    /// the surrounding table applies.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: (1fr, 1fr),
+    ///   align: right,
+    ///   [1.5], [2.25],
+    ///   table.cell(align: left)[Total], [3.75],
+    /// )
+    /// ```
+    pub align: Smart<Axes<Option<GenAlign>>>,
+
+    /// How much to pad the cell's content. If set to `{auto}`, the inset of
+    /// the surrounding table applies.
+    pub inset: Smart<Rel<Length>>,
+}
+
+impl Show for GridCell {
+    fn show(&self, _: &mut Vt, _: StyleChain) -> SourceResult<Content> {
+        Ok(self.body())
+    }
+}
+
 /// Track sizing definitions.
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct TrackSizings(pub Vec<Sizing>);
@@ -138,10 +205,8 @@ cast! {
 
 /// Performs grid layout.
 pub struct GridLayouter<'a> {
-    /// The grid cells.
-    cells: &'a [Content],
-    /// Whether this is an RTL grid.
-    is_rtl: bool,
+    /// The grid slots in row-major order, indexed by track.
+    slots: Vec<Slot>,
     /// Whether this grid has gutters.
     has_gutter: bool,
     /// The column tracks including gutter tracks.
@@ -164,6 +229,12 @@ pub struct GridLayouter<'a> {
     initial: Size,
     /// Frames for finished regions.
     finished: Vec<Frame>,
+    /// The number of leading tracks to repeat at the top of each region.
+    header: usize,
+    /// Whether the header was laid out completely and should be repeated.
+    repeat: bool,
+    /// The height of the header rows.
+    header_height: Abs,
 }
 
 /// The resulting sizes of columns and rows in a grid.
@@ -175,6 +246,9 @@ pub struct GridLayout {
     pub cols: Vec<Abs>,
     /// The heights of the resulting rows segments, by region.
     pub rows: Vec<Vec<RowPiece>>,
+    /// For each track slot in row-major order, the top-left track of the cell
+    /// covering it. This is `None` for gutter and empty slots.
+    pub parents: Vec<Option<Axes<usize>>>,
 }
 
 /// Details about a resulting row piece.
@@ -186,6 +260,57 @@ pub struct RowPiece {
     pub y: usize,
 }
 
+/// A slot in the grid.
+#[derive(Debug, Clone)]
+enum Slot {
+    /// A gutter or otherwise empty slot.
+    Empty,
+    /// The top-left slot of a cell.
+    Cell(Cell),
+    /// A slot covered by the cell whose top-left slot is at the given track.
+    Merged(Axes<usize>),
+}
+
+/// A cell placed in the grid.
+#[derive(Debug, Clone)]
+pub(crate) struct Cell {
+    /// The cell's content.
+    body: Content,
+    /// The span of the cell in the source code.
+    source: Span,
+    /// The position of the cell in content columns and rows, not counting
+    /// gutter tracks.
+    pos: Axes<usize>,
+    /// The number of tracks the cell spans, including gutter tracks.
+    span: Axes<usize>,
+    /// The cell's own alignment.
+    align: Smart<Axes<Option<GenAlign>>>,
+    /// The cell's own inset.
+    inset: Smart<Rel<Length>>,
+}
+
+impl Cell {
+    /// The position of the cell in content columns and rows, not counting
+    /// gutter tracks.
+    pub(crate) fn pos(&self) -> Axes<usize> {
+        self.pos
+    }
+
+    /// Pad and align the cell's content, preferring the cell's own inset and
+    /// alignment over the given defaults.
+    pub(crate) fn finish(&mut self, inset: Rel<Length>, align: Smart<Axes<Option<GenAlign>>>) {
+        let inset = self.inset.unwrap_or(inset);
+        if !inset.is_zero() {
+            self.body = std::mem::take(&mut self.body).padded(Sides::splat(inset));
+        }
+
+        if let Smart::Custom(alignment) = self.align.or(align) {
+            self.body = std::mem::take(&mut self.body)
+                .styled(AlignElem::set_alignment(alignment));
+        }
+    }
+}
+
 /// Produced by initial row layout, auto and relative rows are already finished,
 /// fractional rows not yet.
 enum Row {
@@ -198,24 +323,68 @@ enum Row {
 impl<'a> GridLayouter<'a> {
     /// Create a new grid layouter.
     ///
-    /// This prepares grid layout by unifying content and gutter tracks.
+    /// This prepares grid layout by placing the cells and unifying content and
+    /// gutter tracks.
     pub fn new(
         tracks: Axes<&[Sizing]>,
         gutter: Axes<&[Sizing]>,
-        cells: &'a [Content],
+        cells: &[Content],
         regions: Regions<'a>,
         styles: StyleChain<'a>,
-    ) -> Self {
+    ) -> SourceResult<Self> {
         let mut cols = vec![];
         let mut rows = vec![];
 
         // Number of content columns: Always at least one.
         let c = tracks.x.len().max(1);
 
+        // Place the cells in row-major order, skipping slots that are already
+        // covered by a spanning cell.
+        let mut occupied: Vec<bool> = vec![];
+        let mut placed = vec![];
+        let mut cursor = 0;
+        for child in cells {
+            let (body, span, align, inset) = match child.to::<GridCell>() {
+                Some(cell) => (
+                    cell.body(),
+                    Axes::new(cell.colspan(styles).get(), cell.rowspan(styles).get()),
+                    cell.align(styles),
+                    cell.inset(styles),
+                ),
+                None => (child.clone(), Axes::splat(1), Smart::Auto, Smart::Auto),
+            };
+
+            while occupied.get(cursor).copied().unwrap_or(false) {
+                cursor += 1;
+            }
+
+            let pos = Axes::new(cursor % c, cursor / c);
+            if pos.x + span.x > c {
+                bail!(child.span(), "cell would span beyond the last column");
+            }
+
+            for y in pos.y..pos.y + span.y {
+                for x in pos.x..pos.x + span.x {
+                    let i = y * c + x;
+                    if i >= occupied.len() {
+                        occupied.resize(i + 1, false);
+                    }
+                    if occupied[i] {
+                        bail!(child.span(), "cell would overlap with another cell");
+                    }
+                    occupied[i] = true;
+                }
+            }
+
+            let source = child.span();
+            placed.push(Cell { body, source, pos, span, align, inset });
+            cursor += span.x;
+        }
+
         // Number of content rows: At least as many as given, but also at least
         // as many as needed to place each item.
         let r = {
-            let len = cells.len();
+            let len = occupied.len();
             let given = tracks.y.len();
             let needed = len / c + (len % c).clamp(0, 1);
             given.max(needed)
@@ -256,14 +425,35 @@ impl<'a> GridLayouter<'a> {
             cols.reverse();
         }
 
+        // Map the placed cells onto the tracks. Even tracks are content and
+        // odd ones are gutter if there is gutter.
+        let mut slots = vec![Slot::Empty; cols.len() * rows.len()];
+        for mut cell in placed {
+            let factor = if has_gutter { 2 } else { 1 };
+            let mut start = cell.pos.map(|v| v * factor);
+            cell.span = cell.span.map(|v| v * factor - (factor - 1));
+
+            // Columns are reversed, so the cell starts at its other end.
+            if is_rtl {
+                start.x = cols.len() - start.x - cell.span.x;
+            }
+
+            for y in start.y..start.y + cell.span.y {
+                for x in start.x..start.x + cell.span.x {
+                    slots[y * cols.len() + x] = Slot::Merged(start);
+                }
+            }
+
+            slots[start.y * cols.len() + start.x] = Slot::Cell(cell);
+        }
+
         // We use these regions for auto row measurement. Since at that moment,
         // columns are already sized, we can enable horizontal expansion.
         let mut regions = regions;
         regions.expand = Axes::new(true, false);
 
-        Self {
-            cells,
-            is_rtl,
+        Ok(Self {
+            slots,
             has_gutter,
             rows,
             regions,
@@ -275,7 +465,31 @@ impl<'a> GridLayouter<'a> {
             lrows: vec![],
             initial: regions.size,
             finished: vec![],
+            header: 0,
+            repeat: false,
+            header_height: Abs::zero(),
+        })
+    }
+
+    /// Repeat the first `rows` content rows at the top of each region the
+    /// grid continues in.
+    pub fn repeat_header(&mut self, rows: usize) {
+        // Also repeat the gutter below the header.
+        let tracks = if self.has_gutter { 2 * rows } else { rows };
+        self.header = tracks.min(self.rows.len());
+    }
+
+    /// Transform all cells in place, before they are measured.
+    pub(crate) fn map_cells<F>(&mut self, mut f: F) -> SourceResult<()>
+    where
+        F: FnMut(&mut Cell) -> SourceResult<()>,
+    {
+        for slot in &mut self.slots {
+            if let Slot::Cell(cell) = slot {
+                f(cell)?;
+            }
         }
+        Ok(())
     }
 
     /// Determines the columns sizes and then layouts the grid row-by-row.
@@ -289,22 +503,57 @@ impl<'a> GridLayouter<'a> {
                 self.finish_region(vt)?;
             }
 
-            match self.rows[y] {
-                Sizing::Auto => self.layout_auto_row(vt, y)?,
-                Sizing::Rel(v) => self.layout_relative_row(vt, v, y)?,
-                Sizing::Fr(v) => self.lrows.push(Row::Fr(v, y)),
+            self.layout_row(vt, y)?;
+
+            // Only start repeating once the header itself is done.
+            if self.header > 0 && y + 1 == self.header {
+                self.repeat = true;
+                self.header_height = self
+                    .lrows
+                    .iter()
+                    .filter_map(|row| match row {
+                        Row::Frame(frame, i) if *i < self.header => Some(frame.height()),
+                        _ => None,
+                    })
+                    .sum();
             }
         }
 
+        // Don't repeat the header after the last region.
+        self.repeat = false;
         self.finish_region(vt)?;
 
+        let parents = self
+            .slots
+            .iter()
+            .enumerate()
+            .map(|(i, slot)| match slot {
+                Slot::Empty => None,
+                Slot::Cell(_) => {
+                    Some(Axes::new(i % self.cols.len(), i / self.cols.len()))
+                }
+                Slot::Merged(parent) => Some(*parent),
+            })
+            .collect();
+
         Ok(GridLayout {
             fragment: Fragment::frames(self.finished),
             cols: self.rcols,
             rows: self.rrows,
+            parents,
         })
     }
 
+    /// Layout the row with index `y` according to its sizing.
+    fn layout_row(&mut self, vt: &mut Vt, y: usize) -> SourceResult<()> {
+        match self.rows[y] {
+            Sizing::Auto => self.layout_auto_row(vt, y)?,
+            Sizing::Rel(v) => self.layout_relative_row(vt, v, y)?,
+            Sizing::Fr(v) => self.lrows.push(Row::Fr(v, y)),
+        }
+        Ok(())
+    }
+
     /// Determine all column sizes.
     #[tracing::instrument(name = "GridLayouter::measure_columns", skip_all)]
     fn measure_columns(&mut self, vt: &mut Vt) -> SourceResult<()> {
@@ -370,30 +619,74 @@ impl<'a> GridLayouter<'a> {
             let mut resolved = Abs::zero();
             for y in 0..self.rows.len() {
                 if let Some(cell) = self.cell(x, y) {
-                    // For relative rows, we can already resolve the correct
-                    // base and for auto and fr we could only guess anyway.
-                    let height = match self.rows[y] {
-                        Sizing::Rel(v) => {
-                            v.resolve(self.styles).relative_to(self.regions.base().y)
-                        }
-                        _ => self.regions.base().y,
-                    };
-
-                    let size = Size::new(available, height);
-                    let pod = Regions::one(size, Axes::splat(false));
-                    let frame = cell.measure(vt, self.styles, pod)?.into_frame();
-                    resolved.set_max(frame.width());
+                    // Cells spanning multiple columns are considered below.
+                    if cell.span.x == 1 {
+                        let width = self.measure_cell_width(vt, cell, y, available)?;
+                        resolved.set_max(width);
+                    }
                 }
             }
 
             self.rcols[x] = resolved;
-            auto += resolved;
             count += 1;
         }
 
+        // Make sure that cells spanning multiple columns fit by growing the
+        // last auto column they span. Spans with a fractional column are left
+        // alone, since they will grow into the remaining space anyway.
+        for y in 0..self.rows.len() {
+            for x in 0..self.cols.len() {
+                let Some(cell) = self.cell(x, y) else { continue };
+                let spanned = &self.cols[x..x + cell.span.x];
+                if cell.span.x == 1 || spanned.iter().any(|col| col.is_fractional()) {
+                    continue;
+                }
+
+                let Some(last) = spanned.iter().rposition(|&col| col == Sizing::Auto)
+                else {
+                    continue;
+                };
+
+                let width = self.measure_cell_width(vt, cell, y, available)?;
+                let current: Abs = self.rcols[x..x + cell.span.x].iter().sum();
+                if width > current {
+                    self.rcols[x + last] += width - current;
+                }
+            }
+        }
+
+        for (&col, &rcol) in self.cols.iter().zip(&self.rcols) {
+            if col == Sizing::Auto {
+                auto += rcol;
+            }
+        }
+
         Ok((auto, count))
     }
 
+    /// Measure the natural width of a cell starting in row `y`.
+    fn measure_cell_width(
+        &self,
+        vt: &mut Vt,
+        cell: &Cell,
+        y: usize,
+        available: Abs,
+    ) -> SourceResult<Abs> {
+        // For relative rows, we can already resolve the correct base and for
+        // auto and fr we could only guess anyway.
+        let height = match self.rows[y] {
+            Sizing::Rel(v) if cell.span.y == 1 => {
+                v.resolve(self.styles).relative_to(self.regions.base().y)
+            }
+            _ => self.regions.base().y,
+        };
+
+        let size = Size::new(available, height);
+        let pod = Regions::one(size, Axes::splat(false));
+        let frame = cell.body.measure(vt, self.styles, pod)?.into_frame();
+        Ok(frame.width())
+    }
+
     /// Distribute remaining space to fractional columns.
     fn grow_fractional_columns(&mut self, remaining: Abs, fr: Fr) {
         if fr.is_zero() {
@@ -467,9 +760,10 @@ impl<'a> GridLayouter<'a> {
 
         // Expand all but the last region.
         // Skip the first region if the space is eaten up by an fr row.
+        let mut backlog = vec![];
+        let regions = self.row_regions(&mut backlog);
         let len = resolved.len();
-        for (region, target) in self
-            .regions
+        for (region, target) in regions
             .iter()
             .zip(&mut resolved[..len - 1])
             .skip(self.lrows.iter().any(|row| matches!(row, Row::Fr(..))) as usize)
@@ -499,13 +793,20 @@ impl<'a> GridLayouter<'a> {
         can_skip: bool,
     ) -> SourceResult<Option<Vec<Abs>>> {
         let mut resolved: Vec<Abs> = vec![];
+        let mut backlog = vec![];
+        let regions = self.row_regions(&mut backlog);
 
-        for (x, &rcol) in self.rcols.iter().enumerate() {
+        for x in 0..self.cols.len() {
             if let Some(cell) = self.cell(x, y) {
-                let mut pod = self.regions;
-                pod.size.x = rcol;
+                // Cells spanning multiple rows are considered below.
+                if cell.span.y > 1 {
+                    continue;
+                }
+
+                let mut pod = regions;
+                pod.size.x = self.span_width(x, cell);
 
-                let frames = cell.measure(vt, self.styles, pod)?.into_frames();
+                let frames = cell.body.measure(vt, self.styles, pod)?.into_frames();
 
                 // Skip the first region if one cell in it is empty. Then,
                 // remeasure.
@@ -529,9 +830,58 @@ impl<'a> GridLayouter<'a> {
             }
         }
 
+        // Grow the row if a cell spanning multiple rows ends in it and doesn't
+        // fit into the rows it spans in the current region.
+        for x in 0..self.cols.len() {
+            let Slot::Merged(parent) = self.slots[y * self.cols.len() + x] else {
+                continue;
+            };
+
+            let Some(cell) = self.cell(parent.x, parent.y) else { continue };
+            if parent.x != x || parent.y + cell.span.y != y + 1 {
+                continue;
+            }
+
+            let size = Size::new(self.span_width(x, cell), self.regions.base().y);
+            let pod = Regions::one(size, Axes::splat(false));
+            let height = cell.body.measure(vt, self.styles, pod)?.into_frame().height();
+            let prior: Abs = self
+                .lrows
+                .iter()
+                .filter_map(|row| match row {
+                    Row::Frame(frame, i) if (parent.y..y).contains(i) => {
+                        Some(frame.height())
+                    }
+                    _ => None,
+                })
+                .sum();
+
+            let needed = height - prior;
+            match resolved.first_mut() {
+                Some(first) => first.set_max(needed),
+                None => resolved.push(needed.max(Abs::zero())),
+            }
+        }
+
         Ok(Some(resolved))
     }
 
+    /// The regions an auto row can break across. The following regions leave
+    /// room for the header that is repeated at their top.
+    fn row_regions<'v>(&self, backlog: &'v mut Vec<Abs>) -> Regions<'v> {
+        let header = if self.repeat { self.header_height } else { Abs::zero() };
+        backlog.clear();
+        backlog.extend(self.regions.backlog.iter().map(|&height| height - header));
+        Regions {
+            size: self.regions.size,
+            full: self.regions.full,
+            backlog,
+            last: self.regions.last.map(|height| height - header),
+            expand: self.regions.expand,
+            root: self.regions.root,
+        }
+    }
+
     /// Layout a row with relative height. Such a row cannot break across
     /// multiple regions, but it may force a region break.
     fn layout_relative_row(
@@ -569,18 +919,18 @@ impl<'a> GridLayouter<'a> {
         let mut output = Frame::new(Size::new(self.width, height));
         let mut pos = Point::zero();
 
-        for (x, &rcol) in self.rcols.iter().enumerate() {
-            if let Some(cell) = self.cell(x, y) {
-                let size = Size::new(rcol, height);
+        for x in 0..self.cols.len() {
+            if let Some(cell) = self.cell(x, y).filter(|cell| cell.span.y == 1) {
+                let size = Size::new(self.span_width(x, cell), height);
                 let mut pod = Regions::one(size, Axes::splat(true));
                 if self.rows[y] == Sizing::Auto {
                     pod.full = self.regions.full;
                 }
-                let frame = cell.layout(vt, self.styles, pod)?.into_frame();
+                let frame = cell.body.layout(vt, self.styles, pod)?.into_frame();
                 output.push_frame(pos, frame);
             }
 
-            pos.x += rcol;
+            pos.x += self.rcols[x];
         }
 
         Ok(output)
@@ -607,18 +957,18 @@ impl<'a> GridLayouter<'a> {
 
         // Layout the row.
         let mut pos = Point::zero();
-        for (x, &rcol) in self.rcols.iter().enumerate() {
-            if let Some(cell) = self.cell(x, y) {
-                pod.size.x = rcol;
+        for x in 0..self.cols.len() {
+            if let Some(cell) = self.cell(x, y).filter(|cell| cell.span.y == 1) {
+                pod.size.x = self.span_width(x, cell);
 
                 // Push the layouted frames into the individual output frames.
-                let fragment = cell.layout(vt, self.styles, pod)?;
+                let fragment = cell.body.layout(vt, self.styles, pod)?;
                 for (output, frame) in outputs.iter_mut().zip(fragment) {
                     output.push_frame(pos, frame);
                 }
             }
 
-            pos.x += rcol;
+            pos.x += self.rcols[x];
         }
 
         Ok(Fragment::frames(outputs))
@@ -671,38 +1021,71 @@ impl<'a> GridLayouter<'a> {
             pos.y += height;
         }
 
+        // Layout cells spanning multiple rows into the combined height of the
+        // rows they span in this region. Such cells can't be split, so all of
+        // their rows must end up in this region.
+        let end = rrows.last().map_or(0, |piece| piece.y + 1);
+        let mut offset = Abs::zero();
+        for (i, piece) in rrows.iter().enumerate() {
+            let mut dx = Abs::zero();
+            for x in 0..self.cols.len() {
+                if let Some(cell) = self.cell(x, piece.y).filter(|cell| cell.span.y > 1) {
+                    if piece.y + cell.span.y > end {
+                        bail!(
+                            cell.source,
+                            "cell spanning multiple rows cannot break across regions"
+                        );
+                    }
+
+                    let height = rrows[i..]
+                        .iter()
+                        .take_while(|other| other.y < piece.y + cell.span.y)
+                        .map(|other| other.height)
+                        .sum();
+                    let size = Size::new(self.span_width(x, cell), height);
+                    let pod = Regions::one(size, Axes::splat(true));
+                    let frame = cell.body.layout(vt, self.styles, pod)?.into_frame();
+                    output.push_frame(Point::new(dx, offset), frame);
+                }
+                dx += self.rcols[x];
+            }
+            offset += piece.height;
+        }
+
         self.finished.push(output);
         self.rrows.push(rrows);
         self.regions.next();
         self.initial = self.regions.size;
 
+        // Repeat the header at the top of the new region. While doing so, we
+        // don't repeat it again if the header itself breaks.
+        if self.repeat {
+            self.repeat = false;
+            for y in 0..self.header {
+                self.layout_row(vt, y)?;
+            }
+            self.repeat = true;
+        }
+
         Ok(())
     }
 
-    /// Get the content of the cell in column `x` and row `y`.
+    /// Get the cell whose top-left slot is in column `x` and row `y`.
     ///
-    /// Returns `None` if it's a gutter cell.
+    /// Returns `None` if it's a gutter slot or covered by another cell.
     #[track_caller]
-    fn cell(&self, mut x: usize, y: usize) -> Option<&'a Content> {
+    fn cell(&self, x: usize, y: usize) -> Option<&Cell> {
         assert!(x < self.cols.len());
         assert!(y < self.rows.len());
-
-        // Columns are reorder, but the cell slice is not.
-        if self.is_rtl {
-            x = self.cols.len() - 1 - x;
+        match &self.slots[y * self.cols.len() + x] {
+            Slot::Cell(cell) => Some(cell),
+            _ => None,
         }
+    }
 
-        if self.has_gutter {
-            // Even columns and rows are children, odd ones are gutter.
-            if x % 2 == 0 && y % 2 == 0 {
-                let c = 1 + self.cols.len() / 2;
-                self.cells.get((y / 2) * c + x / 2)
-            } else {
-                None
-            }
-        } else {
-            let c = self.cols.len();
-            self.cells.get(y * c + x)
-        }
+    /// The combined width of the columns spanned by a cell starting in
+    /// column `x`.
+    fn span_width(&self, x: usize, cell: &Cell) -> Abs {
+        self.rcols[x..x + cell.span.x].iter().sum()
     }
 }
@@ -147,7 +147,13 @@ impl Layout for ListElem {
             cells.push(Content::empty());
             cells.push(marker.clone());
             cells.push(Content::empty());
-            cells.push(item.body().styled(Self::set_depth(Depth)));
+            let body = item.body().styled(Self::set_depth(Depth));
+            cells.push(
+                BlockElem::new()
+                    .with_body(Some(body))
+                    .with_role(Some(Role::ListItem))
+                    .pack(),
+            );
         }
 
         let layouter = GridLayouter::new(
@@ -161,9 +167,11 @@ impl Layout for ListElem {
             &cells,
             regions,
             styles,
-        );
+        )?;
 
-        Ok(layouter.layout(vt)?.fragment)
+        let mut fragment = layouter.layout(vt)?.fragment;
+        fragment.tag(Role::List);
+        Ok(fragment)
     }
 }
 
@@ -237,3 +245,13 @@ impl Fold for Depth {
         outer + 1
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_tagged_list_items() {
+        let document = crate::tests::compile("- One\n- Two\n  - Nested");
+        assert_eq!(crate::tests::struct_elems(&document, "L"), 2);
+        assert_eq!(crate::tests::struct_elems(&document, "LI"), 3);
+    }
+}
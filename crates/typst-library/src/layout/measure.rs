@@ -37,6 +37,16 @@ use crate::prelude::*;
 /// The measure function returns a dictionary with the entries `width` and
 /// `height`, both of type [`length`]($type/length).
 ///
+/// Relative sizes in the measured content are resolved against the `width`
+/// and `height` arguments. Without them, the available space is infinite.
+///
+/// ```example
+/// #style(styles => {
+///   let size = measure(box(width: 50%), styles, width: 100pt)
+///   [Width is #size.width]
+/// })
+/// ```
+///
 /// Display: Measure
 /// Category: layout
 #[func]
@@ -45,11 +55,21 @@ pub fn measure(
     content: Content,
     /// The styles with which to layout the content.
     styles: Styles,
+    /// The width of the area to measure in.
+    #[named]
+    width: Option<Length>,
+    /// The height of the area to measure in.
+    #[named]
+    height: Option<Length>,
     /// The virtual machine.
     vm: &mut Vm,
 ) -> SourceResult<Dict> {
-    let pod = Regions::one(Axes::splat(Abs::inf()), Axes::splat(false));
     let styles = StyleChain::new(&styles);
+    let size = Size::new(
+        width.map_or(Abs::inf(), |width| width.resolve(styles)),
+        height.map_or(Abs::inf(), |height| height.resolve(styles)),
+    );
+    let pod = Regions::one(size, Axes::splat(false));
     let frame = content.measure(&mut vm.vt, styles, pod)?.into_frame();
     let Size { x, y } = frame.size();
     Ok(dict! { "width" => x, "height" => y })
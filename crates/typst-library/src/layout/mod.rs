@@ -304,8 +304,13 @@ struct Builder<'a, 'v, 't> {
     par: ParBuilder<'a>,
     /// The current list building state.
     list: ListBuilder<'a>,
+    /// How many show rule results are currently being accepted.
+    depth: usize,
 }
 
+/// The maximum number of nested show rule applications.
+const MAX_SHOW_RULE_DEPTH: usize = 64;
+
 /// Temporary storage arenas for building.
 #[derive(Default)]
 struct Scratch<'a> {
@@ -324,6 +329,7 @@ impl<'a, 'v, 't> Builder<'a, 'v, 't> {
             flow: FlowBuilder::default(),
             par: ParBuilder::default(),
             list: ListBuilder::default(),
+            depth: 0,
         }
     }
 
@@ -338,8 +344,16 @@ impl<'a, 'v, 't> Builder<'a, 'v, 't> {
         }
 
         if let Some(realized) = realize(self.vt, content, styles)? {
+            // A show rule whose output matches itself would recurse forever.
+            if self.depth >= MAX_SHOW_RULE_DEPTH {
+                bail!(content.span(), "maximum show rule depth exceeded");
+            }
+
+            self.depth += 1;
             let stored = self.scratch.content.alloc(realized);
-            return self.accept(stored, styles);
+            let result = self.accept(stored, styles);
+            self.depth -= 1;
+            return result;
         }
 
         if let Some((elem, local)) = content.to_styled() {
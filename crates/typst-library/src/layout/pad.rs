@@ -87,7 +87,8 @@ impl Layout for PadElem {
             let offset = Point::new(padding.left, padding.top);
 
             // Grow the frame and translate everything in the frame inwards.
-            frame.set_size(padded);
+            // Negative padding must not make the frame negatively sized.
+            frame.set_size(padded.max(Size::zero()));
             frame.translate(offset);
         }
 
@@ -438,12 +438,15 @@ impl PageElem {
                 };
 
                 let pod = Regions::one(area, Axes::splat(true));
-                let sub = content
+                let mut sub = content
                     .clone()
                     .styled(AlignElem::set_alignment(align))
                     .layout(vt, styles, pod)?
                     .into_frame();
 
+                // Marginals are not part of the document's logical structure.
+                sub.tag(Role::Artifact);
+
                 if ptr::eq(marginal, &header) || ptr::eq(marginal, &background) {
                     frame.prepend_frame(pos, sub);
                 } else {
@@ -897,3 +900,92 @@ papers! {
     (PRESENTATION_16_9:    297.0, 167.0625, "presentation-16-9")
     (PRESENTATION_4_3:     280.0,    210.0, "presentation-4-3")
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Counts the chunks of pages that are laid out instead of taken from the
+    /// cache.
+    #[derive(Default, Clone)]
+    struct Chunks(Arc<AtomicUsize>);
+
+    impl Subscriber for Chunks {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes) -> Id {
+            if span.metadata().name() == "FlowLayouter::layout_pages" {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event) {}
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    /// Compile a document with rectangles of the given heights and bodies,
+    /// returning the number of pages and of chunks of pages that had to be
+    /// laid out.
+    fn compile(rects: &[(u32, String)]) -> (usize, usize) {
+        let mut text = String::from(
+            "#set page(width: 100pt, height: 100pt, margin: 10pt)\n\
+             #set block(spacing: 10pt)\n",
+        );
+        for (height, body) in rects {
+            text += &format!("#rect(height: {height}pt)[{body}]\n");
+        }
+
+        let chunks = Chunks::default();
+        let document = tracing::subscriber::with_default(chunks.clone(), || {
+            crate::tests::compile(&text)
+        });
+
+        (document.pages.len(), chunks.0.load(Ordering::Relaxed))
+    }
+
+    /// Nine rectangles, three of which fit onto a page.
+    fn rects(prefix: &str) -> Vec<(u32, String)> {
+        (1..=9).map(|i| (20, format!("{prefix}{i}"))).collect()
+    }
+
+    #[test]
+    fn test_edit_on_second_page_reuses_other_pages() {
+        let mut rects = rects("A");
+        let (pages, _) = compile(&rects);
+        assert_eq!(pages, 3);
+
+        // Only the chunk with the edited page is laid out again.
+        rects[4].1 = "Edited".into();
+        let (pages, chunks) = compile(&rects);
+        assert_eq!(pages, 3);
+        assert_eq!(chunks, 1);
+    }
+
+    #[test]
+    fn test_edit_that_shifts_pagination_relays_following_pages() {
+        let mut rects = rects("B");
+        let (pages, _) = compile(&rects);
+        assert_eq!(pages, 3);
+
+        // The taller rectangle on the second page pushes the rectangles after
+        // it onto later pages, so only the first page is reused.
+        rects[4].0 = 30;
+        let (pages, chunks) = compile(&rects);
+        assert_eq!(pages, 4);
+        assert_eq!(chunks, 3);
+    }
+}
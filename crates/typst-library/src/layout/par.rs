@@ -107,6 +107,44 @@ pub struct ParElem {
     #[resolve]
     pub hanging_indent: Length,
 
+    /// The maximum number of lines of the paragraph. Lines beyond it are
+    /// dropped.
+    ///
+    /// ```example
+    /// #set par(max-lines: 2)
+    /// #lorem(20)
+    /// ```
+    pub max_lines: Option<NonZeroUsize>,
+
+    /// Whether to mark a paragraph that was truncated because of
+    /// [`max-lines`]($func/par.max-lines) with an ellipsis.
+    #[default(true)]
+    pub ellipsis: bool,
+
+    /// The minimum number of lines of the paragraph. Missing lines are filled
+    /// with empty space.
+    ///
+    /// ```example
+    /// #set par(min-lines: 3)
+    /// #rect[Short]
+    /// ```
+    pub min_lines: Option<NonZeroUsize>,
+
+    /// The distance between tab stops. A tab character advances the text to
+    /// the next multiple of this distance from the start of the line.
+    ///
+    /// In justified paragraphs, tabs keep their width and only the other
+    /// spaces are stretched.
+    ///
+    /// ```example
+    /// #set par(tab-size: 3em)
+    /// #"1.\tFirst" \
+    /// #"10.\tTenth"
+    /// ```
+    #[resolve]
+    #[default(Em::new(2.0).into())]
+    pub tab_size: Length,
+
     /// The contents of the paragraph.
     #[external]
     #[required]
@@ -180,7 +218,9 @@ impl ParElem {
             let lines = linebreak(&vt, &p, region.x - p.hang);
 
             // Stack the lines into one frame per region.
-            finalize(&mut vt, &p, &lines, region, expand)
+            let mut fragment = finalize(&mut vt, &p, &lines, region, expand)?;
+            fragment.tag(Role::Paragraph);
+            Ok(fragment)
         }
 
         let fragment = cached(
@@ -324,8 +364,9 @@ enum Segment<'a> {
     /// One or multiple collapsed text or text-equivalent children. Stores how
     /// long the segment is (in bytes of the full text string).
     Text(usize),
-    /// Horizontal spacing between other segments.
-    Spacing(Spacing),
+    /// Horizontal spacing between other segments. Stores whether relative
+    /// spacing is resolved against the space that remains on the line.
+    Spacing(Spacing, bool),
     /// A mathematical equation.
     Equation(&'a EquationElem),
     /// A box with arbitrary content.
@@ -339,7 +380,7 @@ impl Segment<'_> {
     fn len(&self) -> usize {
         match *self {
             Self::Text(len) => len,
-            Self::Spacing(_) => SPACING_REPLACE.len_utf8(),
+            Self::Spacing(_, _) => SPACING_REPLACE.len_utf8(),
             Self::Box(_, true) => SPACING_REPLACE.len_utf8(),
             Self::Equation(_) | Self::Box(_, _) => OBJ_REPLACE.len_utf8(),
             Self::Meta => 0,
@@ -354,8 +395,13 @@ enum Item<'a> {
     Text(ShapedText<'a>),
     /// Absolute spacing between other items.
     Absolute(Abs),
+    /// Spacing between other items that is relative to the space that
+    /// remains on the line.
+    Remaining(Rel<Abs>),
     /// Fractional spacing between other items.
     Fractional(Fr, Option<(&'a BoxElem, StyleChain<'a>)>),
+    /// A tab that advances to the next multiple of the tab size.
+    Tab(Abs),
     /// Layouted inline-level content.
     Frame(Frame),
     /// Metadata.
@@ -382,23 +428,39 @@ impl<'a> Item<'a> {
     fn len(&self) -> usize {
         match self {
             Self::Text(shaped) => shaped.text.len(),
-            Self::Absolute(_) | Self::Fractional(_, _) => SPACING_REPLACE.len_utf8(),
+            Self::Absolute(_) | Self::Remaining(_) | Self::Fractional(_, _) => {
+                SPACING_REPLACE.len_utf8()
+            }
+            Self::Tab(_) => '\t'.len_utf8(),
             Self::Frame(_) => OBJ_REPLACE.len_utf8(),
             Self::Meta(_) => 0,
         }
     }
 
     /// The natural layouted width of the item.
+    ///
+    /// The width of a tab depends on its position and is not included.
     fn width(&self) -> Abs {
         match self {
             Self::Text(shaped) => shaped.width,
             Self::Absolute(v) => *v,
+            Self::Remaining(v) => v.abs,
             Self::Frame(frame) => frame.width(),
-            Self::Fractional(_, _) | Self::Meta(_) => Abs::zero(),
+            Self::Fractional(_, _) | Self::Tab(_) | Self::Meta(_) => Abs::zero(),
         }
     }
 }
 
+/// How far a tab at the natural offset `x` advances to reach the next tab
+/// stop.
+fn tab_advance(x: Abs, size: Abs) -> Abs {
+    if size <= Abs::zero() {
+        return Abs::zero();
+    }
+
+    size * ((x / size).floor() + 1.0) - x
+}
+
 /// Maps byte offsets back to spans.
 #[derive(Default)]
 pub struct SpanMapper(Vec<(usize, Span)>);
@@ -522,6 +584,16 @@ impl<'a> Line<'a> {
         self.items().filter_map(Item::text).map(|s| s.shrinkability()).sum()
     }
 
+    /// The sum of the ratios of spacing relative to the remaining space.
+    fn remaining_ratio(&self) -> f64 {
+        self.items()
+            .filter_map(|item| match item {
+                Item::Remaining(v) => Some(v.rel.get()),
+                _ => None,
+            })
+            .sum()
+    }
+
     /// The sum of fractions in the line.
     fn fr(&self) -> Fr {
         self.items()
@@ -554,13 +626,13 @@ fn collect<'a>(
             == TextElem::dir_in(*styles).start().into()
     {
         full.push(SPACING_REPLACE);
-        segments.push((Segment::Spacing(first_line_indent.into()), *styles));
+        segments.push((Segment::Spacing(first_line_indent.into(), false), *styles));
     }
 
     let hang = ParElem::hanging_indent_in(*styles);
     if !hang.is_zero() {
         full.push(SPACING_REPLACE);
-        segments.push((Segment::Spacing((-hang).into()), *styles));
+        segments.push((Segment::Spacing((-hang).into(), false), *styles));
     }
 
     while let Some(mut child) = iter.next() {
@@ -588,7 +660,7 @@ fn collect<'a>(
             }
 
             full.push(SPACING_REPLACE);
-            Segment::Spacing(elem.amount())
+            Segment::Spacing(elem.amount(), elem.remaining(styles))
         } else if let Some(elem) = child.to::<LinebreakElem>() {
             let c = if elem.justify(styles) { '\u{2028}' } else { '\n' };
             full.push(c);
@@ -690,9 +762,41 @@ fn prepare<'a>(
         let end = cursor + segment.len();
         match segment {
             Segment::Text(_) => {
-                shape_range(&mut items, vt, &bidi, cursor..end, &spans, styles);
+                let lang = TextElem::lang_in(styles);
+                if TextElem::hyphenate_explicitly_in(styles)
+                    && hyphenation_lang(lang).is_none()
+                    && !TextElem::hyphenation_dicts_in(styles).contains(lang)
+                {
+                    vt.tracer.warn(
+                        warning!(
+                            spans.span_at(cursor).0,
+                            "no hyphenation patterns for language {:?}",
+                            lang.as_str(),
+                        )
+                        .with_hint("text in this language will not be hyphenated"),
+                    );
+                }
+
+                // Shape the text between tabs, which become items of their
+                // own.
+                let mut start = cursor;
+                for (i, _) in text[cursor..end].match_indices('\t') {
+                    let tab = cursor + i;
+                    if start < tab {
+                        shape_range(&mut items, vt, &bidi, start..tab, &spans, styles);
+                    }
+                    items.push(Item::Tab(ParElem::tab_size_in(styles)));
+                    start = tab + 1;
+                }
+
+                if start < end || start == cursor {
+                    shape_range(&mut items, vt, &bidi, start..end, &spans, styles);
+                }
             }
-            Segment::Spacing(spacing) => match spacing {
+            Segment::Spacing(spacing, remaining) => match spacing {
+                Spacing::Rel(v) if remaining => {
+                    items.push(Item::Remaining(v.resolve(styles)));
+                }
                 Spacing::Rel(v) => {
                     let resolved = v.resolve(styles).relative_to(region.x);
                     items.push(Item::Absolute(resolved));
@@ -744,7 +848,7 @@ fn prepare<'a>(
 /// items for them.
 fn shape_range<'a>(
     items: &mut Vec<Item<'a>>,
-    vt: &Vt,
+    vt: &mut Vt,
     bidi: &BidiInfo<'a>,
     range: Range,
     spans: &SpanMapper,
@@ -757,6 +861,8 @@ fn shape_range<'a>(
         let dir = if level.is_ltr() { Dir::LTR } else { Dir::RTL };
         let shaped =
             shape(vt, range.start, &bidi.text[range], spans, styles, dir, lang, region);
+        shaped.warn_uncovered(vt);
+        shaped.warn_synthesized(vt);
         items.push(Item::Text(shaped));
     };
 
@@ -1104,7 +1210,8 @@ fn breakpoints<'a>(p: &'a Preparation<'a>) -> Breakpoints<'a> {
     Breakpoints {
         p,
         linebreaks,
-        syllables: None,
+        syllables: vec![],
+        pieces: vec![],
         offset: 0,
         suffix: 0,
         end: 0,
@@ -1118,8 +1225,12 @@ struct Breakpoints<'a> {
     p: &'a Preparation<'a>,
     /// The inner iterator over the unicode line break opportunities.
     linebreaks: LineBreakIteratorUtf8<'a, 'a>,
-    /// Iterator over syllables of the current word.
-    syllables: Option<hypher::Syllables<'a>>,
+    /// The byte lengths of the remaining syllables of the current word, in
+    /// reverse order.
+    syllables: Vec<usize>,
+    /// The remaining breaks inside of the current unbroken string, in reverse
+    /// order and ending with the end of the string.
+    pieces: Vec<usize>,
     /// The current text offset.
     offset: usize,
     /// The trimmed end of the current word.
@@ -1135,8 +1246,8 @@ impl Iterator for Breakpoints<'_> {
 
     fn next(&mut self) -> Option<Self::Item> {
         // If we're currently in a hyphenated "word", process the next syllable.
-        if let Some(syllable) = self.syllables.as_mut().and_then(Iterator::next) {
-            self.offset += syllable.len();
+        if let Some(syllable) = self.syllables.pop() {
+            self.offset += syllable;
             if self.offset == self.suffix {
                 self.offset = self.end;
             }
@@ -1151,6 +1262,12 @@ impl Iterator for Breakpoints<'_> {
             return Some((self.offset, self.mandatory && !hyphen, hyphen));
         }
 
+        // If we're currently in a breakable string, process the next piece.
+        if let Some(offset) = self.pieces.pop() {
+            self.offset = offset;
+            return Some((offset, self.mandatory && offset == self.end, false));
+        }
+
         let lb = LINEBREAK_DATA.as_borrowed();
 
         // Get the next "word".
@@ -1166,14 +1283,43 @@ impl Iterator for Breakpoints<'_> {
                 ) || self.end == self.p.bidi.text.len()
             });
 
+        // Break long unbroken strings like URLs after punctuation.
+        if self.breakable(self.offset) {
+            let word = &self.p.bidi.text[self.offset..self.end];
+            let mut chars = word.char_indices().peekable();
+            let mut prev: Option<char> = None;
+            while let Some((_, c)) = chars.next() {
+                if let Some(&(i, next)) = chars.peek() {
+                    // Keep decimal numbers like version numbers together.
+                    let decimal = c == '.'
+                        && prev.map_or(false, |p| p.is_ascii_digit())
+                        && next.is_ascii_digit();
+                    if is_url_break(c)
+                        && !is_url_break(next)
+                        && !next.is_whitespace()
+                        && !decimal
+                    {
+                        self.pieces.push(self.offset + i);
+                    }
+                }
+                prev = Some(c);
+            }
+
+            if !self.pieces.is_empty() {
+                self.pieces.push(self.end);
+                self.pieces.reverse();
+                return self.next();
+            }
+        }
+
         // Hyphenate the next word.
         if self.p.hyphenate != Some(false) {
-            if let Some(lang) = self.lang(self.offset) {
-                let word = &self.p.bidi.text[self.offset..self.end];
-                let trimmed = word.trim_end_matches(|c: char| !c.is_alphabetic());
-                if !trimmed.is_empty() {
+            let word = &self.p.bidi.text[self.offset..self.end];
+            let trimmed = word.trim_end_matches(|c: char| !c.is_alphabetic());
+            if !trimmed.is_empty() {
+                if let Some(syllables) = self.syllables(trimmed) {
                     self.suffix = self.offset + trimmed.len();
-                    self.syllables = Some(hypher::hyphenate(trimmed, lang));
+                    self.syllables = syllables;
                     return self.next();
                 }
             }
@@ -1196,18 +1342,77 @@ impl Breakpoints<'_> {
             .unwrap_or(false)
     }
 
-    /// The text language at the given offset.
-    fn lang(&self, offset: usize) -> Option<hypher::Lang> {
-        let lang = self.p.lang.or_else(|| {
-            let shaped = self.p.find(offset)?.text()?;
-            Some(TextElem::lang_in(shaped.styles))
-        })?;
+    /// Whether breaking after punctuation is enabled at the given offset.
+    fn breakable(&self, offset: usize) -> bool {
+        self.p
+            .find(offset)
+            .and_then(|item| item.text())
+            .map_or(false, |shaped| TextElem::breakable_in(shaped.styles))
+    }
 
-        let bytes = lang.as_str().as_bytes().try_into().ok()?;
-        hypher::Lang::from_iso(bytes)
+    /// Split a word starting at the current offset into syllables, returning
+    /// their byte lengths in reverse order.
+    ///
+    /// Uses the custom dictionary of the text language if it has an entry
+    /// for the word and the bundled patterns otherwise.
+    fn syllables(&self, word: &str) -> Option<Vec<usize>> {
+        let styles = self.p.find(self.offset)?.text()?.styles;
+        let lang = self.p.lang.unwrap_or_else(|| TextElem::lang_in(styles));
+
+        let lengths: Vec<usize> = if let Some(entry) =
+            TextElem::hyphenation_dicts_in(styles).get(lang, word)
+        {
+            // Map the entry's syllables onto the word, which may differ
+            // in case.
+            let mut chars = word.chars();
+            entry
+                .split('-')
+                .map(|part| {
+                    chars.by_ref().take(part.chars().count()).map(char::len_utf8).sum()
+                })
+                .collect()
+        } else {
+            hypher::hyphenate(word, hyphenation_lang(lang)?).map(str::len).collect()
+        };
+
+        // Merge syllables that would leave too few characters before the
+        // first or after the last hyphen.
+        let before = TextElem::hyphenate_before_in(styles).unwrap_or(0);
+        let after = TextElem::hyphenate_after_in(styles).unwrap_or(0);
+        let total = word.chars().count();
+        let mut syllables = vec![];
+        let mut offset = 0;
+        let mut pending = 0;
+        for len in lengths {
+            offset += len;
+            pending += len;
+            let count = word[..offset].chars().count();
+            if count >= before && total - count >= after {
+                syllables.push(pending);
+                pending = 0;
+            }
+        }
+
+        if offset < word.len() || pending > 0 {
+            syllables.push(pending + word.len() - offset);
+        }
+
+        syllables.reverse();
+        Some(syllables)
     }
 }
 
+/// Whether a line may break after this character in a breakable string.
+fn is_url_break(c: char) -> bool {
+    matches!(c, '/' | '-' | '.' | '?' | '&' | '=' | '#' | '_')
+}
+
+/// The hyphenation patterns for a language, if there are any.
+fn hyphenation_lang(lang: Lang) -> Option<hypher::Lang> {
+    let bytes = lang.as_str().as_bytes().try_into().ok()?;
+    hypher::Lang::from_iso(bytes)
+}
+
 /// Create a line which spans the given range.
 fn line<'a>(
     vt: &Vt,
@@ -1337,6 +1542,18 @@ fn line<'a>(
         width += item.width();
     }
 
+    // Tabs advance from the natural width of the items before them.
+    let mut x = Abs::zero();
+    for item in first.iter().chain(inner).chain(&last) {
+        if let Item::Tab(size) = item {
+            let advance = tab_advance(x, *size);
+            width += advance;
+            x += advance;
+        } else {
+            x += item.width();
+        }
+    }
+
     Line {
         bidi: &p.bidi,
         trimmed: range,
@@ -1351,21 +1568,46 @@ fn line<'a>(
 }
 
 /// Combine layouted lines into one frame per region.
-fn finalize(
+fn finalize<'a>(
     vt: &mut Vt,
-    p: &Preparation,
-    lines: &[Line],
+    p: &'a Preparation<'a>,
+    lines: &[Line<'a>],
     region: Size,
     expand: bool,
 ) -> SourceResult<Fragment> {
+    // Limit the number of lines and shape an ellipsis if lines are dropped.
+    let max = ParElem::max_lines_in(p.styles).map_or(usize::MAX, NonZeroUsize::get);
+    let mark = if lines.len() > max && ParElem::ellipsis_in(p.styles) {
+        ellipsis(vt, p, &lines[max - 1])
+    } else {
+        None
+    };
+
+    // Make room for the ellipsis by rebuilding the last line so that it ends
+    // early enough.
+    let kept = &lines[..lines.len().min(max)];
+    let shortened = match (&mark, kept.last()) {
+        (Some(mark), Some(last)) => {
+            Some(shorten(vt, p, last, region.x - p.hang - mark.width()))
+        }
+        _ => None,
+    };
+
+    let lines: Vec<&Line> = match &shortened {
+        Some(line) => kept[..kept.len() - 1].iter().chain([line]).collect(),
+        None => kept.iter().collect(),
+    };
+
+    let extra = mark.as_ref().map_or(Abs::zero(), Frame::width);
+
     // Determine the paragraph's width: Full width of the region if we
     // should expand or there's fractional spacing, fit-to-width otherwise.
     let width = if !region.x.is_finite()
         || (!expand && lines.iter().all(|line| line.fr().is_zero()))
     {
-        region
-            .x
-            .min(p.hang + lines.iter().map(|line| line.width).max().unwrap_or_default())
+        let natural = lines.iter().map(|line| line.width).max().unwrap_or_default();
+        let last = lines.last().map_or(Abs::zero(), |line| line.width + extra);
+        region.x.min(p.hang + natural.max(last))
     } else {
         region.x
     };
@@ -1376,6 +1618,24 @@ fn finalize(
         .map(|line| commit(vt, p, line, width, region.y))
         .collect::<SourceResult<_>>()?;
 
+    // Append the ellipsis to the last line, right after its text.
+    if let (Some(mark), Some(frame)) = (mark, frames.last_mut()) {
+        let end = frame
+            .items()
+            .filter_map(|(pos, item)| match item {
+                FrameItem::Text(text) => Some(pos.x + text.width()),
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default();
+        let x = end.min(frame.width() - mark.width()).max(Abs::zero());
+        let y = frame.baseline() - mark.baseline();
+        frame.push_frame(Point::new(x, y), mark);
+    }
+
+    // Remember the height of a single line before lines are merged below.
+    let line_height = frames.last().map(Frame::height);
+
     // Prevent orphans.
     let leading = ParElem::leading_in(p.styles);
     if frames.len() >= 2 && !frames[1].is_empty() {
@@ -1392,9 +1652,64 @@ fn finalize(
         merge(first, second, leading);
     }
 
+    // Fill up missing lines.
+    let min = ParElem::min_lines_in(p.styles).map_or(0, NonZeroUsize::get);
+    if let Some(height) = line_height {
+        for _ in lines.len()..min {
+            frames.push(Frame::new(Size::new(width, height)));
+        }
+    }
+
     Ok(Fragment::frames(frames))
 }
 
+/// Rebuild a line so that it ends at the last breakpoint at which it still fits
+/// into the given width. The rebuilt line is not justified.
+///
+/// If not even the first piece of the line fits, it is rebuilt in full.
+fn shorten<'a>(vt: &Vt, p: &'a Preparation<'a>, full: &Line<'a>, width: Abs) -> Line<'a> {
+    let start = full.trimmed.start;
+    let mut fitting = None;
+    let mut fallback = None;
+
+    for (end, mandatory, hyphen) in breakpoints(p) {
+        if end <= start {
+            continue;
+        } else if end > full.end {
+            break;
+        }
+
+        let attempt = line(vt, p, start..end, mandatory, hyphen);
+        if width.fits(attempt.width) {
+            fitting = Some(attempt);
+        } else if end == full.end {
+            fallback = Some(attempt);
+        }
+    }
+
+    let mut shortened = fitting
+        .or(fallback)
+        .unwrap_or_else(|| line(vt, p, start..full.end, true, false));
+    shortened.justify = false;
+    shortened
+}
+
+/// Shape an ellipsis in the style of the line's last text.
+fn ellipsis(vt: &mut Vt, p: &Preparation, line: &Line) -> Option<Frame> {
+    let text = line.items().filter_map(Item::text).last()?;
+    let shaped = shape(
+        vt,
+        line.end,
+        "…",
+        &p.spans,
+        text.styles,
+        text.dir,
+        text.lang,
+        text.region,
+    );
+    Some(shaped.build(vt, 0.0, Abs::zero()))
+}
+
 /// Merge two line frames
 fn merge(first: &mut Frame, second: Frame, leading: Abs) {
     let offset = first.height() + leading;
@@ -1447,6 +1762,10 @@ fn commit(
         }
     }
 
+    // Spacing relative to the remaining space takes its share first.
+    let leftover = remaining.max(Abs::zero());
+    remaining -= leftover * line.remaining_ratio();
+
     // Determine how much additional space is needed.
     // The justicication_ratio is for the first step justification,
     // extra_justification is for the last step.
@@ -1480,9 +1799,13 @@ fn commit(
     let mut top = Abs::zero();
     let mut bottom = Abs::zero();
 
-    // Build the frames and determine the height and baseline.
+    // Build the frames and determine the height and baseline. Tabs advance
+    // from the natural offset, so that they keep their width when the text
+    // before them is justified.
     let mut frames = vec![];
+    let mut natural = Abs::zero();
     for item in reordered {
+        natural += item.width();
         let mut push = |offset: &mut Abs, frame: Frame| {
             let width = frame.width();
             top.set_max(frame.baseline());
@@ -1495,6 +1818,9 @@ fn commit(
             Item::Absolute(v) => {
                 offset += *v;
             }
+            Item::Remaining(v) => {
+                offset += v.relative_to(leftover);
+            }
             Item::Fractional(v, elem) => {
                 let amount = v.share(fr, remaining);
                 if let Some((elem, styles)) = elem {
@@ -1511,6 +1837,11 @@ fn commit(
                 let frame = shaped.build(vt, justification_ratio, extra_justification);
                 push(&mut offset, frame);
             }
+            Item::Tab(size) => {
+                let advance = tab_advance(natural, *size);
+                offset += advance;
+                natural += advance;
+            }
             Item::Frame(frame) | Item::Meta(frame) => {
                 push(&mut offset, frame.clone());
             }
@@ -1596,3 +1927,70 @@ fn overhang(c: char) -> f64 {
         _ => 0.0,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The number of glyphs on the first line of a word that is hyphenated
+    /// with the given dictionary entry.
+    fn first_line_glyphs(entry: &str) -> usize {
+        let document = crate::tests::compile(&format!(
+            "#set page(width: 40pt, margin: 0pt)\n\
+             #set text(size: 10pt, hyphenate: true)\n\
+             #set text(hyphenation-dicts: (en: ({entry:?},)))\n\
+             qwertyuiop"
+        ));
+
+        crate::tests::items(&document.pages[0])
+            .into_iter()
+            .find_map(|(_, item)| match item {
+                FrameItem::Text(text) => Some(text.glyphs.len()),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_custom_hyphenation_dictionary() {
+        assert_eq!(first_line_glyphs("qw-ertyuiop"), 3);
+        assert_eq!(first_line_glyphs("qwer-tyuiop"), 5);
+    }
+
+    #[test]
+    fn test_ellipsis_fits_after_justified_line() {
+        let document = crate::tests::compile(
+            "#set page(width: 100pt, margin: 0pt)\n\
+             #set par(justify: true, max-lines: 1)\n\
+             #lorem(20)",
+        );
+
+        let texts: Vec<_> = crate::tests::items(&document.pages[0])
+            .into_iter()
+            .filter_map(|(pos, item)| match item {
+                FrameItem::Text(text) => Some((pos, text)),
+                _ => None,
+            })
+            .collect();
+
+        let (mark, ellipsis) = texts.iter().find(|(_, text)| text.text == "…").unwrap();
+        assert!(mark.x + ellipsis.width() <= Abs::pt(100.0));
+        for (pos, text) in &texts {
+            if text.text != "…" {
+                assert!(pos.x + text.width() <= mark.x + Abs::pt(0.01));
+            }
+        }
+    }
+
+    #[test]
+    fn test_tagged_paragraph_spans_pages() {
+        let document = crate::tests::compile(
+            "#set page(height: 100pt, margin: (x: 10pt, y: 30pt), header: [Head])\n\
+             #lorem(200)\n\n\
+             Second paragraph.",
+        );
+
+        assert!(document.pages.len() > 1);
+        assert_eq!(crate::tests::struct_elems(&document, "P"), 2);
+    }
+}
@@ -79,6 +79,19 @@ pub struct PlaceElem {
     /// The vertical displacement of the placed content.
     pub dy: Rel<Length>,
 
+    /// Whether to warn when the placed content overlaps content in the flow.
+    ///
+    /// This helps to catch layout mistakes. Intentional overlaps, like a
+    /// watermark, can opt out even if the lint is enabled by a set rule.
+    ///
+    /// ```example
+    /// #set place(lint: true)
+    /// #place(center, lint: false, text(30pt, luma(200))[DRAFT])
+    /// Hello, world!
+    /// ```
+    #[default(false)]
+    pub lint: bool,
+
     /// The content to place.
     #[required]
     pub body: Content,
@@ -142,3 +142,43 @@ impl Debug for Regions<'_> {
         list.finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regions_next_pulls_from_backlog() {
+        let size = Size::new(Abs::pt(100.0), Abs::pt(30.0));
+        let backlog = [Abs::pt(20.0)];
+        let mut regions = Regions {
+            backlog: &backlog,
+            ..Regions::one(size, Axes::splat(false))
+        };
+
+        assert!(!regions.in_last());
+        let sizes: Vec<_> = regions.iter().collect();
+        assert_eq!(sizes, [size, Size::new(Abs::pt(100.0), Abs::pt(20.0))]);
+
+        regions.next();
+        assert_eq!(regions.size, Size::new(Abs::pt(100.0), Abs::pt(20.0)));
+        assert_eq!(regions.full, Abs::pt(20.0));
+        assert!(regions.in_last());
+
+        // Without a final region, the last region stays.
+        regions.next();
+        assert_eq!(regions.size.y, Abs::pt(20.0));
+    }
+
+    #[test]
+    fn test_regions_repeat() {
+        let size = Size::new(Abs::pt(100.0), Abs::pt(30.0));
+        let mut regions = Regions::repeat(size, Axes::splat(true));
+        regions.size.y = Abs::pt(5.0);
+        assert!(!regions.in_last());
+        regions.next();
+        assert_eq!(regions.size, size);
+        assert!(regions.in_last());
+        assert!(regions.iter().take(3).all(|s| s == size));
+    }
+}
@@ -31,6 +31,31 @@ pub struct RepeatElem {
     /// The content to repeat.
     #[required]
     pub body: Content,
+
+    /// The gap between each instance of the body.
+    ///
+    /// ```example
+    /// #box(width: 1fr, repeat(gap: 0.3em)[.])
+    /// ```
+    #[resolve]
+    pub gap: Length,
+
+    /// Whether to increase the gap between instances to completely fill the
+    /// available space.
+    ///
+    /// If disabled, the instances keep their fixed gap and are aligned as a
+    /// whole according to the current [alignment]($func/align). With an end
+    /// alignment, the instances of several leaders that end at the same
+    /// position line up, regardless of the content before them.
+    ///
+    /// ```example
+    /// #set align(end)
+    /// #let leader = box(width: 1fr, repeat(gap: 2pt, justify: false)[.])
+    /// Intro #leader 1 \
+    /// Background #leader 3
+    /// ```
+    #[default(true)]
+    pub justify: bool,
 }
 
 impl Layout for RepeatElem {
@@ -47,9 +72,13 @@ impl Layout for RepeatElem {
 
         let fill = regions.size.x;
         let width = piece.width();
-        let count = (fill / width).floor();
-        let remaining = fill % width;
-        let apart = remaining / (count - 1.0);
+        let gap = self.gap(styles);
+        let justify = self.justify(styles);
+
+        // The gap only sits between instances, so count one extra gap.
+        let count = ((fill + gap) / (width + gap)).floor();
+        let remaining = (fill + gap) % (width + gap);
+        let apart = if justify { gap + remaining / (count - 1.0) } else { gap };
 
         let size = Size::new(regions.size.x, piece.height());
 
@@ -63,7 +92,7 @@ impl Layout for RepeatElem {
         }
 
         let mut offset = Abs::zero();
-        if count == 1.0 {
+        if count == 1.0 || !justify {
             offset += align.position(remaining);
         }
 
@@ -54,6 +54,15 @@ pub struct HElem {
     /// ```
     #[default(false)]
     pub weak: bool,
+
+    /// If `{true}`, a relative amount is resolved against the space that
+    /// remains on the line instead of the paragraph's full width.
+    ///
+    /// ```example
+    /// Start #h(50%, remaining: true) halfway through the rest.
+    /// ```
+    #[default(false)]
+    pub remaining: bool,
 }
 
 impl Behave for HElem {
@@ -122,6 +131,18 @@ pub struct VElem {
     #[internal]
     #[parse(args.named("weak")?.map(|v: bool| v as usize))]
     pub weakness: usize,
+
+    /// If `{true}`, a relative amount is resolved against the space that
+    /// remains in the region instead of the region's full height.
+    ///
+    /// ```example
+    /// #set page(height: 120pt)
+    /// #v(20pt)
+    /// #v(50%, remaining: true)
+    /// Starts halfway down the rest of the page.
+    /// ```
+    #[default(false)]
+    pub remaining: bool,
 }
 
 impl VElem {
@@ -1,6 +1,6 @@
 use typst::eval::{CastInfo, Reflect};
 
-use crate::layout::{AlignElem, GridLayouter, TrackSizings};
+use crate::layout::{GridCell, GridLayouter, RowPiece, TrackSizings};
 use crate::meta::{Figurable, LocalName};
 use crate::prelude::*;
 
@@ -38,6 +38,10 @@ use crate::prelude::*;
 /// Display: Table
 /// Category: layout
 #[element(Layout, LocalName, Figurable)]
+#[scope(
+    scope.define("cell", GridCell::func());
+    scope
+)]
 pub struct TableElem {
     /// The column sizes. See the [grid documentation]($func/grid) for more
     /// information on track sizing.
@@ -92,7 +96,8 @@ pub struct TableElem {
     /// This can either be a single alignment, an array of alignments
     /// (corresponding to each column) or a function that returns an alignment.
     /// The function is passed the cells' column and row index, starting at zero.
-    /// If set to `{auto}`, the outer alignment is used.
+    /// If set to `{auto}`, the outer alignment is used. Individual cells can
+    /// override this with [`table.cell`]($func/grid.cell.align).
     ///
     /// ```example
     /// #table(
@@ -104,24 +109,61 @@ pub struct TableElem {
     /// ```
     pub align: Celled<Smart<Axes<Option<GenAlign>>>>,
 
-    /// How to stroke the cells.
+    /// How to stroke the lines between and around the cells.
     ///
     /// See the [line's documentation]($func/line.stroke) for more details.
     /// Strokes can be disabled by setting this to `{none}`.
     ///
+    /// To style the lines individually, pass a dictionary. The keys `left`,
+    /// `top`, `right`, and `bottom` refer to the lines around the table, `x`
+    /// to the lines between columns, and `y` to the lines between rows. The
+    /// key `outer` sets all lines around the table, `inner` all lines between
+    /// cells, and `rest` all lines that are not otherwise specified. Lines
+    /// without a stroke are not drawn.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 3,
+    ///   stroke: (outer: 1pt, y: 0.5pt + luma(150)),
+    ///   [A], [B], [C],
+    ///   [D], [E], [F],
+    /// )
+    /// ```
+    ///
     /// _Note:_ Richer stroke customization for individual cells is not yet
     /// implemented, but will be in the future. In the meantime, you can use
     /// the third-party [tablex library](https://github.com/PgBiel/typst-tablex/).
     #[resolve]
     #[fold]
-    #[default(Some(PartialStroke::default()))]
-    pub stroke: Option<PartialStroke>,
+    #[default(TableStroke::splat(Some(PartialStroke::default())))]
+    pub stroke: TableStroke,
 
     /// How much to pad the cells' content.
+    ///
+    /// Individual cells can override this with
+    /// [`table.cell`]($func/grid.cell.inset).
     #[default(Abs::pt(5.0).into())]
     pub inset: Rel<Length>,
 
+    /// The number of rows at the top of the table that are repeated on each
+    /// page the table continues on.
+    ///
+    /// ```example
+    /// #set page(height: 80pt)
+    /// #table(
+    ///   columns: 2,
+    ///   header-rows: 1,
+    ///   [*Name*], [*Score*],
+    ///   ..range(6).map(i => ([Player #i], [#(i * 10)])).flatten(),
+    /// )
+    /// ```
+    #[default(0)]
+    pub header_rows: usize,
+
     /// The contents of the table cells.
+    ///
+    /// To let a cell span multiple columns or rows, wrap it in a
+    /// [`table.cell`]($func/grid.cell).
     #[variadic]
     pub children: Vec<Content>,
 }
@@ -139,35 +181,27 @@ impl Layout for TableElem {
 
         let tracks = Axes::new(self.columns(styles).0, self.rows(styles).0);
         let gutter = Axes::new(self.column_gutter(styles).0, self.row_gutter(styles).0);
-        let cols = tracks.x.len().max(1);
-        let cells: Vec<_> = self
-            .children()
-            .into_iter()
-            .enumerate()
-            .map(|(i, child)| {
-                let mut child = child.padded(Sides::splat(inset));
-
-                let x = i % cols;
-                let y = i / cols;
-                if let Smart::Custom(alignment) = align.resolve(vt, x, y)? {
-                    child = child.styled(AlignElem::set_alignment(alignment));
-                }
-
-                Ok(child)
-            })
-            .collect::<SourceResult<_>>()?;
-
         let fill = self.fill(styles);
-        let stroke = self.stroke(styles).map(PartialStroke::unwrap_or_default);
+        let stroke = self.stroke(styles);
 
         // Prepare grid layout by unifying content and gutter tracks.
-        let layouter = GridLayouter::new(
+        let mut layouter = GridLayouter::new(
             tracks.as_deref(),
             gutter.as_deref(),
-            &cells,
+            &self.children(),
             regions,
             styles,
-        );
+        )?;
+
+        layouter.repeat_header(self.header_rows(styles));
+
+        // Pad and align the cells, unless they override these themselves.
+        layouter.map_cells(|cell| {
+            let pos = cell.pos();
+            let align = align.resolve(vt, pos.x, pos.y)?;
+            cell.finish(inset, align);
+            Ok(())
+        })?;
 
         // Measure the columns and layout the grid row-by-row.
         let mut layout = layouter.layout(vt)?;
@@ -178,38 +212,28 @@ impl Layout for TableElem {
                 continue;
             }
 
+            // The top-left track of the cell covering a slot.
+            let parent = |x: usize, y: usize| layout.parents[y * layout.cols.len() + x];
+
             // Render table lines.
-            if let Some(stroke) = &stroke {
-                let thickness = stroke.thickness;
-                let half = thickness / 2.0;
-
-                // Render horizontal lines.
-                for offset in points(rows.iter().map(|piece| piece.height)) {
-                    let target = Point::with_x(frame.width() + thickness);
-                    let hline = Geometry::Line(target).stroked(stroke.clone());
-                    frame.prepend(
-                        Point::new(-half, offset),
-                        FrameItem::Shape(hline, self.span()),
-                    );
-                }
+            let merged = |a: Axes<usize>, b: Axes<usize>| {
+                let first = parent(a.x, a.y);
+                first.is_some() && first == parent(b.x, b.y)
+            };
 
-                // Render vertical lines.
-                for offset in points(layout.cols.iter().copied()) {
-                    let target = Point::with_y(frame.height() + thickness);
-                    let vline = Geometry::Line(target).stroked(stroke.clone());
-                    frame.prepend(
-                        Point::new(offset, -half),
-                        FrameItem::Shape(vline, self.span()),
-                    );
-                }
+            for (pos, line) in lines(&layout.cols, rows, &stroke, merged) {
+                frame.prepend(pos, FrameItem::Shape(line, self.span()));
             }
 
-            // Render cell backgrounds.
+            // Render cell backgrounds. Slots covered by a spanning cell use the
+            // fill of that cell.
             let mut dx = Abs::zero();
             for (x, &col) in layout.cols.iter().enumerate() {
                 let mut dy = Abs::zero();
                 for row in rows {
-                    if let Some(fill) = fill.resolve(vt, x, row.y)? {
+                    let (fx, fy) = parent(x, row.y)
+                        .map_or((x, row.y), |parent| (parent.x, parent.y));
+                    if let Some(fill) = fill.resolve(vt, fx, fy)? {
                         let pos = Point::new(dx, dy);
                         let size = Size::new(col, row.height);
                         let rect = Geometry::Rect(size).filled(fill);
@@ -221,6 +245,7 @@ impl Layout for TableElem {
             }
         }
 
+        layout.fragment.tag(Role::Table);
         Ok(layout.fragment)
     }
 }
@@ -235,6 +260,202 @@ fn points(extents: impl IntoIterator<Item = Abs>) -> impl Iterator<Item = Abs> {
     })
 }
 
+/// Determine the lines of a table region.
+///
+/// Lines are interrupted where both adjacent slots belong to the same spanning
+/// cell, as determined by `merged`. Lines with zero thickness are not drawn.
+fn lines(
+    cols: &[Abs],
+    rows: &[RowPiece],
+    stroke: &TableStroke<Abs>,
+    merged: impl Fn(Axes<usize>, Axes<usize>) -> bool,
+) -> Vec<(Point, Shape)> {
+    let mut lines = vec![];
+    let resolve = |stroke: &Option<PartialStroke<Abs>>| {
+        stroke
+            .clone()
+            .map(PartialStroke::unwrap_or_default)
+            .filter(|stroke| !stroke.thickness.is_zero())
+    };
+
+    // Render horizontal lines.
+    let heights: Vec<_> = rows.iter().map(|piece| piece.height).collect();
+    for (i, dy) in points(heights.iter().copied()).enumerate() {
+        let line = match i {
+            0 => &stroke.outer.top,
+            _ if i == rows.len() => &stroke.outer.bottom,
+            _ => &stroke.inner.y,
+        };
+
+        let Some(line) = resolve(line) else { continue };
+        let half = line.thickness / 2.0;
+        let inner = i > 0 && i < rows.len();
+        let segments = runs(cols, |x| {
+            !inner || !merged(Axes::new(x, rows[i - 1].y), Axes::new(x, rows[i].y))
+        });
+
+        for (start, length) in segments {
+            let target = Point::with_x(length + line.thickness);
+            let hline = Geometry::Line(target).stroked(line.clone());
+            lines.push((Point::new(start - half, dy), hline));
+        }
+    }
+
+    // Render vertical lines.
+    for (x, dx) in points(cols.iter().copied()).enumerate() {
+        let line = match x {
+            0 => &stroke.outer.left,
+            _ if x == cols.len() => &stroke.outer.right,
+            _ => &stroke.inner.x,
+        };
+
+        let Some(line) = resolve(line) else { continue };
+        let half = line.thickness / 2.0;
+        let inner = x > 0 && x < cols.len();
+        let segments = runs(&heights, |i| {
+            !inner || !merged(Axes::new(x - 1, rows[i].y), Axes::new(x, rows[i].y))
+        });
+
+        for (start, length) in segments {
+            let target = Point::with_y(length + line.thickness);
+            let vline = Geometry::Line(target).stroked(line.clone());
+            lines.push((Point::new(dx, start - half), vline));
+        }
+    }
+
+    lines
+}
+
+/// Group consecutive extents for which `draw` returns true into runs of
+/// `(offset, length)`.
+fn runs(extents: &[Abs], mut draw: impl FnMut(usize) -> bool) -> Vec<(Abs, Abs)> {
+    let mut runs = vec![];
+    let mut run: Option<(Abs, Abs)> = None;
+    let mut offset = Abs::zero();
+    for (i, &extent) in extents.iter().enumerate() {
+        if draw(i) {
+            let (_, length) = run.get_or_insert((offset, Abs::zero()));
+            *length += extent;
+        } else if let Some(finished) = run.take() {
+            runs.push(finished);
+        }
+        offset += extent;
+    }
+    runs.extend(run);
+    runs
+}
+
+/// The strokes of the lines in a table.
+#[derive(Debug, Default, Clone, PartialEq, Hash)]
+pub struct TableStroke<T = Length> {
+    /// The lines around the table.
+    pub outer: Sides<Option<PartialStroke<T>>>,
+    /// The lines between columns (`x`) and between rows (`y`).
+    pub inner: Axes<Option<PartialStroke<T>>>,
+}
+
+impl<T: Clone> TableStroke<T> {
+    /// Use the same stroke for all lines.
+    pub fn splat(stroke: Option<PartialStroke<T>>) -> Self {
+        Self {
+            outer: Sides::splat(stroke.clone()),
+            inner: Axes::splat(stroke),
+        }
+    }
+}
+
+impl Resolve for TableStroke {
+    type Output = TableStroke<Abs>;
+
+    fn resolve(self, styles: StyleChain) -> Self::Output {
+        TableStroke {
+            outer: self.outer.resolve(styles),
+            inner: self.inner.map(|line| line.resolve(styles)),
+        }
+    }
+}
+
+impl Fold for TableStroke<Abs> {
+    type Output = Self;
+
+    fn fold(self, outer: Self::Output) -> Self::Output {
+        Self {
+            outer: self.outer.zip(outer.outer).map(|(line, outer)| line.fold(outer)),
+            inner: self.inner.zip(outer.inner).map(|(line, outer)| line.fold(outer)),
+        }
+    }
+}
+
+impl Reflect for TableStroke {
+    fn describe() -> CastInfo {
+        Option::<PartialStroke>::describe() + Dict::describe()
+    }
+
+    fn castable(value: &Value) -> bool {
+        Dict::castable(value) || Option::<PartialStroke>::castable(value)
+    }
+}
+
+impl IntoValue for TableStroke {
+    fn into_value(self) -> Value {
+        let Self { outer, inner } = self;
+        if outer.is_uniform() && inner.x == outer.left && inner.y == outer.left {
+            return outer.left.into_value();
+        }
+
+        let mut dict = Dict::new();
+        let mut handle = |key: &str, line: Option<PartialStroke>| {
+            dict.insert(key.into(), line.into_value());
+        };
+
+        handle("left", outer.left);
+        handle("top", outer.top);
+        handle("right", outer.right);
+        handle("bottom", outer.bottom);
+        handle("x", inner.x);
+        handle("y", inner.y);
+
+        Value::Dict(dict)
+    }
+}
+
+impl FromValue for TableStroke {
+    fn from_value(mut value: Value) -> StrResult<Self> {
+        let keys =
+            ["left", "top", "right", "bottom", "outer", "x", "y", "inner", "rest"];
+        if let Value::Dict(dict) = &mut value {
+            if dict.iter().any(|(key, _)| keys.contains(&key.as_str())) {
+                let mut take = |key| {
+                    dict.take(key)
+                        .ok()
+                        .map(Option::<PartialStroke>::from_value)
+                        .transpose()
+                };
+                let rest = take("rest")?.flatten();
+                let outer = take("outer")?.unwrap_or_else(|| rest.clone());
+                let inner = take("inner")?.unwrap_or_else(|| rest.clone());
+                let stroke = Self {
+                    outer: Sides {
+                        left: take("left")?.unwrap_or_else(|| outer.clone()),
+                        top: take("top")?.unwrap_or_else(|| outer.clone()),
+                        right: take("right")?.unwrap_or_else(|| outer.clone()),
+                        bottom: take("bottom")?.unwrap_or_else(|| outer.clone()),
+                    },
+                    inner: Axes::new(
+                        take("x")?.unwrap_or_else(|| inner.clone()),
+                        take("y")?.unwrap_or_else(|| inner.clone()),
+                    ),
+                };
+
+                dict.finish(&keys)?;
+                return Ok(stroke);
+            }
+        }
+
+        Option::<PartialStroke>::from_value(value).map(Self::splat)
+    }
+}
+
 /// A value that can be configured per cell.
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub enum Celled<T> {
@@ -332,3 +553,66 @@ impl LocalName for TableElem {
 }
 
 impl Figurable for TableElem {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stroke(thickness: f64) -> TableStroke<Abs> {
+        TableStroke::splat(Some(PartialStroke {
+            thickness: Smart::Custom(Abs::pt(thickness)),
+            ..Default::default()
+        }))
+    }
+
+    fn layout() -> (Vec<Abs>, Vec<RowPiece>) {
+        let cols = vec![Abs::pt(10.0), Abs::pt(20.0)];
+        let rows = vec![
+            RowPiece { height: Abs::pt(5.0), y: 0 },
+            RowPiece { height: Abs::pt(5.0), y: 1 },
+        ];
+        (cols, rows)
+    }
+
+    fn line(pos: (f64, f64), target: (f64, f64)) -> (Point, Geometry) {
+        let pos = Point::new(Abs::pt(pos.0), Abs::pt(pos.1));
+        let target = Point::new(Abs::pt(target.0), Abs::pt(target.1));
+        (pos, Geometry::Line(target))
+    }
+
+    fn geometries(lines: Vec<(Point, Shape)>) -> Vec<(Point, Geometry)> {
+        lines.into_iter().map(|(pos, shape)| (pos, shape.geometry)).collect()
+    }
+
+    #[test]
+    fn test_table_lines() {
+        let (cols, rows) = layout();
+        let lines = lines(&cols, &rows, &stroke(1.0), |_, _| false);
+        assert_eq!(
+            geometries(lines),
+            [
+                line((-0.5, 0.0), (31.0, 0.0)),
+                line((-0.5, 5.0), (31.0, 0.0)),
+                line((-0.5, 10.0), (31.0, 0.0)),
+                line((0.0, -0.5), (0.0, 11.0)),
+                line((10.0, -0.5), (0.0, 11.0)),
+                line((30.0, -0.5), (0.0, 11.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_table_lines_span() {
+        // The first row is a single cell spanning both columns.
+        let (cols, rows) = layout();
+        let merged = |a: Axes<usize>, b: Axes<usize>| a.y == 0 && b.y == 0;
+        let lines = lines(&cols, &rows, &stroke(1.0), merged);
+        assert_eq!(geometries(lines)[4], line((10.0, 4.5), (0.0, 6.0)));
+    }
+
+    #[test]
+    fn test_table_lines_zero_thickness() {
+        let (cols, rows) = layout();
+        assert!(lines(&cols, &rows, &stroke(0.0), |_, _| false).is_empty());
+    }
+}
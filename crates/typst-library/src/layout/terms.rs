@@ -131,9 +131,11 @@ impl Layout for TermsElem {
             seq.push(child.description());
         }
 
-        Content::sequence(seq)
+        let mut fragment = Content::sequence(seq)
             .styled(ParElem::set_hanging_indent(hanging_indent + indent))
-            .layout(vt, styles, regions)
+            .layout(vt, styles, regions)?;
+        fragment.tag(Role::List);
+        Ok(fragment)
     }
 }
 
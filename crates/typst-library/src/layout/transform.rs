@@ -101,6 +101,24 @@ pub struct RotateElem {
     #[default(Align::CENTER_HORIZON)]
     pub origin: Axes<Option<GenAlign>>,
 
+    /// Whether the rotation affects the layout.
+    ///
+    /// If set to `{true}`, the content is laid out into the available space as
+    /// seen from the rotated content, so that text in a sideways rotated
+    /// element wraps according to the available height instead of the width.
+    /// For rotations closer to a quarter turn than to a half turn, the
+    /// available width and height are swapped. The rotated element then takes
+    /// up the size of its rotated bounding box, and the `origin` is ignored.
+    ///
+    /// ```example
+    /// #set page(height: 80pt)
+    /// #box(rotate(90deg, reflow: true)[
+    ///   This text runs along the page.
+    /// ])
+    /// ```
+    #[default(false)]
+    pub reflow: bool,
+
     /// The content to rotate.
     #[required]
     pub body: Content,
@@ -114,18 +132,54 @@ impl Layout for RotateElem {
         styles: StyleChain,
         regions: Regions,
     ) -> SourceResult<Fragment> {
+        let angle = self.angle(styles);
+        if self.reflow(styles) {
+            return self.layout_reflowed(vt, styles, regions, angle);
+        }
+
         let pod = Regions::one(regions.base(), Axes::splat(false));
         let mut frame = self.body().layout(vt, styles, pod)?.into_frame();
         let Axes { x, y } =
             self.origin(styles).zip(frame.size()).map(|(o, s)| o.position(s));
         let ts = Transform::translate(x, y)
-            .pre_concat(Transform::rotate(self.angle(styles)))
+            .pre_concat(Transform::rotate(angle))
             .pre_concat(Transform::translate(-x, -y));
         frame.transform(ts);
         Ok(Fragment::frame(frame))
     }
 }
 
+impl RotateElem {
+    /// Layout the body into the rotated available space and size the result
+    /// to its rotated bounding box.
+    fn layout_reflowed(
+        &self,
+        vt: &mut Vt,
+        styles: StyleChain,
+        regions: Regions,
+        angle: Angle,
+    ) -> SourceResult<Fragment> {
+        let (sin, cos) = (angle.sin().abs(), angle.cos().abs());
+        let mut base = regions.base();
+        if sin > cos {
+            base = Size::new(base.y, base.x);
+        }
+
+        let pod = Regions::one(base, Axes::splat(false));
+        let mut frame = self.body().layout(vt, styles, pod)?.into_frame();
+        let Size { x: w, y: h } = frame.size();
+        let size = Size::new(w * cos + h * sin, w * sin + h * cos);
+
+        // Rotate around the center and move it into the bounding box's center.
+        let ts = Transform::translate(size.x / 2.0, size.y / 2.0)
+            .pre_concat(Transform::rotate(angle))
+            .pre_concat(Transform::translate(-w / 2.0, -h / 2.0));
+        frame.transform(ts);
+        frame.set_size(size);
+        Ok(Fragment::frame(frame))
+    }
+}
+
 /// Scales content without affecting layout.
 ///
 /// Lets you mirror content by specifying a negative scale on a single axis.
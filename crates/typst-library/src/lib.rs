@@ -14,6 +14,9 @@ pub mod symbols;
 pub mod text;
 pub mod visualize;
 
+#[cfg(test)]
+mod tests;
+
 use typst::diag::At;
 use typst::eval::{LangItems, Library, Module, Scope};
 use typst::geom::Smart;
@@ -296,6 +296,7 @@ impl GlyphFragment {
             font: self.font.clone(),
             size: self.font_size,
             fill: self.fill,
+            stroke: None,
             lang: self.lang,
             text: self.c.into(),
             glyphs: vec![Glyph {
@@ -301,6 +301,7 @@ impl Show for FigureElem {
         // Wrap the contents in a block.
         realized = BlockElem::new()
             .with_body(Some(realized))
+            .with_role(Some(Role::Figure))
             .pack()
             .aligned(Axes::with_x(Some(Align::Center.into())));
 
@@ -155,7 +155,10 @@ impl Show for HeadingElem {
                 + HElem::new(Em::new(0.3).into()).with_weak(true).pack()
                 + realized;
         }
-        Ok(BlockElem::new().with_body(Some(realized)).pack())
+        Ok(BlockElem::new()
+            .with_body(Some(realized))
+            .with_role(Some(Role::Heading(self.level(styles))))
+            .pack())
     }
 }
 
@@ -267,3 +270,56 @@ impl LocalName for HeadingElem {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_tagged_pdf_heading_before_paragraph() {
+        let document = crate::tests::compile("= Introduction\nSome text.");
+        let pdf = typst::export::pdf_tagged(&document);
+        let pdf = String::from_utf8_lossy(&pdf);
+
+        assert!(pdf.contains("/StructTreeRoot"));
+        let heading = pdf.find("/S /H1").unwrap();
+        let paragraph = pdf.find("/S /P").unwrap();
+        assert!(heading < paragraph);
+
+        let plain = typst::export::pdf(&document);
+        assert!(!String::from_utf8_lossy(&plain).contains("/StructTreeRoot"));
+    }
+
+    #[test]
+    fn test_pdf_bookmarks_nest_by_level() {
+        let document = crate::tests::compile("= Top\n#pagebreak()\n== Nested");
+        let pdf = typst::export::pdf(&document);
+        let pdf = String::from_utf8_lossy(&pdf);
+
+        let (top, top_body) = object(&pdf, "/Title (Top)");
+        let (nested, nested_body) = object(&pdf, "/Title (Nested)");
+        assert!(top_body.contains(&format!("/First {nested} 0 R")));
+        assert!(top_body.contains("/Count -1"));
+        assert!(nested_body.contains(&format!("/Parent {top} 0 R")));
+
+        let (_, pages) = object(&pdf, "/Type /Pages");
+        let kids = between(pages, "/Kids [", "]");
+        let kids: Vec<_> = kids.split(" 0 R").map(str::trim).collect();
+        assert_eq!(between(top_body, "/Dest [", " 0 R"), kids[0]);
+        assert_eq!(between(nested_body, "/Dest [", " 0 R"), kids[1]);
+    }
+
+    /// The id and body of the indirect object that contains the needle.
+    fn object<'a>(pdf: &'a str, needle: &str) -> (&'a str, &'a str) {
+        let at = pdf.find(needle).unwrap();
+        let start = pdf[..at].rfind(" 0 obj").unwrap();
+        let id = pdf[..start].rsplit('\n').next().unwrap();
+        let end = start + pdf[start..].find("endobj").unwrap();
+        (id, &pdf[start..end])
+    }
+
+    /// The text between the first occurrence of `from` and the next `to`.
+    fn between<'a>(text: &'a str, from: &str, to: &str) -> &'a str {
+        let start = text.find(from).unwrap() + from.len();
+        let end = start + text[start..].find(to).unwrap();
+        &text[start..end]
+    }
+}
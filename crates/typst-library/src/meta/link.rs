@@ -75,6 +75,15 @@ pub struct LinkElem {
         _ => args.expect("body")?,
     })]
     pub body: Content,
+
+    /// A text describing the link, for use by assistive technology.
+    ///
+    /// In PDF export, this becomes the description of the link annotation.
+    ///
+    /// ```example
+    /// #link("https://typst.app", alt: "Typst's website")[Typst]
+    /// ```
+    pub alt: Option<EcoString>,
 }
 
 impl LinkElem {
@@ -87,17 +96,21 @@ impl LinkElem {
 
 impl Show for LinkElem {
     #[tracing::instrument(name = "LinkElem::show", skip(self, vt))]
-    fn show(&self, vt: &mut Vt, _: StyleChain) -> SourceResult<Content> {
+    fn show(&self, vt: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
         let body = self.body();
+        let alt = self.alt(styles);
+        let link = |dest| {
+            let meta = Meta::Link(dest, alt.clone());
+            body.clone().styled(MetaElem::set_data(vec![meta]))
+        };
         let linked = match self.dest() {
-            LinkTarget::Dest(dest) => body.linked(dest),
+            LinkTarget::Dest(dest) => link(dest),
             LinkTarget::Label(label) => vt
                 .delayed(|vt| {
                     let elem = vt.introspector.query_label(&label).at(self.span())?;
-                    let dest = Destination::Location(elem.location().unwrap());
-                    Ok(Some(body.clone().linked(dest)))
+                    Ok(Some(link(Destination::Location(elem.location().unwrap()))))
                 })
-                .unwrap_or(body),
+                .unwrap_or_else(|| body.clone()),
         };
 
         Ok(linked.styled(TextElem::set_hyphenate(Hyphenate(Smart::Custom(false)))))
@@ -135,3 +148,33 @@ impl From<Destination> for LinkTarget {
         Self::Dest(dest)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use typst::model::{Introspector, Label};
+
+    #[test]
+    fn test_pdf_link_jumps_to_named_destination() {
+        let document = crate::tests::compile(
+            "#link(<target>)[Go]\n#pagebreak()\n#v(100pt)\n#metadata(none) <target>",
+        );
+        let pdf = typst::export::pdf(&document);
+        let pdf = String::from_utf8_lossy(&pdf);
+        assert!(pdf.contains("/D /target"));
+
+        let introspector = Introspector::new(&document.pages);
+        let target = introspector.query_label(&Label("target".into())).unwrap();
+        let pos = introspector.position(target.location().unwrap());
+        assert_eq!(pos.page.get(), 2);
+
+        let start = pdf.find("/target [").unwrap() + "/target [".len();
+        let dest = pdf[start..].split(']').next().unwrap();
+        let dest: Vec<_> = dest.split_whitespace().collect();
+        let height = document.pages[1].height().to_pt();
+        let x: f64 = dest[4].parse().unwrap();
+        let y: f64 = dest[5].parse().unwrap();
+        assert_eq!(dest[3], "/XYZ");
+        assert!((x - pos.point.x.to_pt()).abs() < 0.01);
+        assert!((y - (height - pos.point.y.to_pt() + 10.0)).abs() < 0.01);
+    }
+}
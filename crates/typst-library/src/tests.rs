@@ -0,0 +1,111 @@
+//! Helpers for tests that need to compile documents.
+
+use comemo::Prehashed;
+use typst::diag::{FileError, FileResult};
+use typst::doc::{Document, Frame, FrameItem, GroupItem};
+use typst::eval::{Bytes, Datetime, Library, Tracer};
+use typst::font::{Font, FontBook};
+use typst::geom::Point;
+use typst::syntax::{FileId, Source};
+use typst::World;
+
+const FONT: &[u8] = include_bytes!("../../../assets/fonts/LinLibertine_R.ttf");
+
+/// Compile a document from source text with a single font.
+pub fn compile(text: &str) -> Document {
+    let font = Font::new(FONT.into(), 0).unwrap();
+    let world = TestWorld {
+        library: Prehashed::new(crate::build()),
+        book: Prehashed::new(FontBook::from_fonts([&font])),
+        font,
+        source: Source::detached(text),
+    };
+
+    typst::compile(&world, &mut Tracer::new()).unwrap()
+}
+
+/// Collect all non-group items in the frame with their absolute positions.
+///
+/// Transforms of groups are not taken into account.
+pub fn items(frame: &Frame) -> Vec<(Point, FrameItem)> {
+    fn walk(frame: &Frame, offset: Point, out: &mut Vec<(Point, FrameItem)>) {
+        for (pos, item) in frame.items() {
+            match item {
+                FrameItem::Group(group) => walk(&group.frame, offset + *pos, out),
+                _ => out.push((offset + *pos, item.clone())),
+            }
+        }
+    }
+
+    let mut out = vec![];
+    walk(frame, Point::zero(), &mut out);
+    out
+}
+
+/// Collect all groups in the frame, including nested ones.
+pub fn groups(frame: &Frame) -> Vec<GroupItem> {
+    fn walk(frame: &Frame, out: &mut Vec<GroupItem>) {
+        for (_, item) in frame.items() {
+            if let FrameItem::Group(group) = item {
+                out.push(group.clone());
+                walk(&group.frame, out);
+            }
+        }
+    }
+
+    let mut out = vec![];
+    walk(frame, &mut out);
+    out
+}
+
+/// Count the structure elements of the given type, like `P` for paragraphs,
+/// in the tagged PDF export of a document.
+pub fn struct_elems(document: &Document, kind: &str) -> usize {
+    let pdf = typst::export::pdf_tagged(document);
+    let pdf = String::from_utf8_lossy(&pdf);
+    let tokens: Vec<_> = pdf.split_whitespace().collect();
+    let name = format!("/{kind}");
+    tokens.windows(2).filter(|pair| *pair == ["/S", name.as_str()]).count()
+}
+
+/// A world with a single source file and font.
+struct TestWorld {
+    library: Prehashed<Library>,
+    book: Prehashed<FontBook>,
+    font: Font,
+    source: Source,
+}
+
+impl World for TestWorld {
+    fn library(&self) -> &Prehashed<Library> {
+        &self.library
+    }
+
+    fn book(&self) -> &Prehashed<FontBook> {
+        &self.book
+    }
+
+    fn main(&self) -> Source {
+        self.source.clone()
+    }
+
+    fn source(&self, id: FileId) -> FileResult<Source> {
+        if id == self.source.id() {
+            Ok(self.source.clone())
+        } else {
+            Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
+        }
+    }
+
+    fn file(&self, id: FileId) -> FileResult<Bytes> {
+        Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
+    }
+
+    fn font(&self, _: usize) -> Option<Font> {
+        Some(self.font.clone())
+    }
+
+    fn today(&self, _: Option<i64>) -> Option<Datetime> {
+        None
+    }
+}
@@ -73,11 +73,12 @@ impl Show for UnderlineElem {
     #[tracing::instrument(name = "UnderlineElem::show", skip_all)]
     fn show(&self, _: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
         Ok(self.body().styled(TextElem::set_deco(Decoration {
-            line: DecoLine::Underline,
-            stroke: self.stroke(styles).unwrap_or_default(),
-            offset: self.offset(styles),
+            line: DecoLine::Underline {
+                stroke: self.stroke(styles).unwrap_or_default(),
+                offset: self.offset(styles),
+                evade: self.evade(styles),
+            },
             extent: self.extent(styles),
-            evade: self.evade(styles),
         })))
     }
 }
@@ -157,11 +158,12 @@ impl Show for OverlineElem {
     #[tracing::instrument(name = "OverlineElem::show", skip_all)]
     fn show(&self, _: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
         Ok(self.body().styled(TextElem::set_deco(Decoration {
-            line: DecoLine::Overline,
-            stroke: self.stroke(styles).unwrap_or_default(),
-            offset: self.offset(styles),
+            line: DecoLine::Overline {
+                stroke: self.stroke(styles).unwrap_or_default(),
+                offset: self.offset(styles),
+                evade: self.evade(styles),
+            },
             extent: self.extent(styles),
-            evade: self.evade(styles),
         })))
     }
 }
@@ -226,23 +228,82 @@ impl Show for StrikeElem {
     #[tracing::instrument(name = "StrikeElem::show", skip_all)]
     fn show(&self, _: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
         Ok(self.body().styled(TextElem::set_deco(Decoration {
-            line: DecoLine::Strikethrough,
-            stroke: self.stroke(styles).unwrap_or_default(),
-            offset: self.offset(styles),
+            line: DecoLine::Strikethrough {
+                stroke: self.stroke(styles).unwrap_or_default(),
+                offset: self.offset(styles),
+            },
             extent: self.extent(styles),
-            evade: false,
         })))
     }
 }
 
-/// Defines a line that is positioned over, under or on top of text.
+/// Highlights text with a background color.
+///
+/// Unlike a [filled box]($func/box.fill), the highlight follows the text
+/// across line breaks.
+///
+/// ## Example { #example }
+/// ```example
+/// This is #highlight[important].
+/// ```
+///
+/// Display: Highlight
+/// Category: text
+#[element(Show)]
+pub struct HighlightElem {
+    /// The color to highlight the text with.
+    ///
+    /// ```example
+    /// This is #highlight(fill: aqua)[highlighted with aqua].
+    /// ```
+    #[default(Color::Rgba(RgbaColor::new(0xFF, 0xFD, 0x11, 0xA1)).into())]
+    pub fill: Paint,
+
+    /// The amount by which to extend the background to the sides beyond (or
+    /// within if negative) the content.
+    ///
+    /// ```example
+    /// A long #highlight(extent: 4pt)[background].
+    /// ```
+    #[resolve]
+    pub extent: Length,
+
+    /// How much to round the background's corners. See the [rectangle's
+    /// documentation]($func/rect.radius) for more details.
+    ///
+    /// ```example
+    /// Listen #highlight(
+    ///   radius: 5pt, extent: 2pt
+    /// )[carefully], it will be on the test.
+    /// ```
+    #[resolve]
+    #[fold]
+    pub radius: Corners<Option<Rel<Length>>>,
+
+    /// The content that should be highlighted.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for HighlightElem {
+    #[tracing::instrument(name = "HighlightElem::show", skip_all)]
+    fn show(&self, _: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
+        Ok(self.body().styled(TextElem::set_deco(Decoration {
+            line: DecoLine::Highlight {
+                fill: self.fill(styles),
+                radius: self.radius(styles).unwrap_or_default(),
+            },
+            extent: self.extent(styles),
+        })))
+    }
+}
+
+/// Defines a line that is positioned over, under or on top of text, or a
+/// background behind it.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Decoration {
     pub line: DecoLine,
-    pub stroke: PartialStroke<Abs>,
-    pub offset: Smart<Abs>,
     pub extent: Abs,
-    pub evade: bool,
 }
 
 impl Fold for Decoration {
@@ -259,11 +320,12 @@ cast! {
 }
 
 /// A kind of decorative line.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum DecoLine {
-    Underline,
-    Strikethrough,
-    Overline,
+    Underline { stroke: PartialStroke<Abs>, offset: Smart<Abs>, evade: bool },
+    Strikethrough { stroke: PartialStroke<Abs>, offset: Smart<Abs> },
+    Overline { stroke: PartialStroke<Abs>, offset: Smart<Abs>, evade: bool },
+    Highlight { fill: Paint, radius: Corners<Rel<Abs>> },
 }
 
 /// Add line decorations to a single run of shaped text.
@@ -276,14 +338,34 @@ pub(super) fn decorate(
     width: Abs,
 ) {
     let font_metrics = text.font.metrics();
-    let metrics = match deco.line {
-        DecoLine::Strikethrough => font_metrics.strikethrough,
-        DecoLine::Overline => font_metrics.overline,
-        DecoLine::Underline => font_metrics.underline,
+
+    if let DecoLine::Highlight { fill, radius } = &deco.line {
+        let top = font_metrics.ascender.at(text.size);
+        let bottom = font_metrics.descender.at(text.size);
+        let size = Size::new(width + 2.0 * deco.extent, top - bottom);
+        let radius = radius.map(|side| side.relative_to(size.x.min(size.y) / 2.0));
+        let origin = Point::new(pos.x - deco.extent, pos.y - top - shift);
+        for shape in rounded_rect(size, radius, Some(fill.clone()), Sides::splat(None)) {
+            frame.push(origin, FrameItem::Shape(shape, Span::detached()));
+        }
+        return;
+    }
+
+    let (stroke, metrics, offset, evade) = match &deco.line {
+        DecoLine::Strikethrough { stroke, offset } => {
+            (stroke, font_metrics.strikethrough, offset, false)
+        }
+        DecoLine::Overline { stroke, offset, evade } => {
+            (stroke, font_metrics.overline, offset, *evade)
+        }
+        DecoLine::Underline { stroke, offset, evade } => {
+            (stroke, font_metrics.underline, offset, *evade)
+        }
+        DecoLine::Highlight { .. } => unreachable!(),
     };
 
-    let offset = deco.offset.unwrap_or(-metrics.position.at(text.size)) - shift;
-    let stroke = deco.stroke.clone().unwrap_or(Stroke {
+    let offset = offset.unwrap_or(-metrics.position.at(text.size)) - shift;
+    let stroke = stroke.clone().unwrap_or(Stroke {
         paint: text.fill.clone(),
         thickness: metrics.thickness.at(text.size),
         ..Stroke::default()
@@ -299,13 +381,13 @@ pub(super) fn decorate(
         let origin = Point::new(from, pos.y + offset);
         let target = Point::new(to - from, Abs::zero());
 
-        if target.x >= min_width || !deco.evade {
+        if target.x >= min_width || !evade {
             let shape = Geometry::Line(target).stroked(stroke.clone());
             frame.push(origin, FrameItem::Shape(shape, Span::detached()));
         }
     };
 
-    if !deco.evade {
+    if !evade {
         push_segment(start, end);
         return;
     }
@@ -418,3 +500,65 @@ impl OutlineBuilder for BezPathBuilder {
         self.path.close_path();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An underline as laid out and as defined by the font.
+    struct Measured {
+        baseline: Abs,
+        y: Abs,
+        thickness: Abs,
+        font_position: Abs,
+        font_thickness: Abs,
+    }
+
+    /// Lay out underlined text with the given arguments.
+    fn underline(args: &str) -> Measured {
+        let document = crate::tests::compile(&format!("#underline({args})[Typst]"));
+        let items = crate::tests::items(&document.pages[0]);
+
+        let (baseline, text) = items
+            .iter()
+            .find_map(|(pos, item)| match item {
+                FrameItem::Text(text) => Some((pos.y, text)),
+                _ => None,
+            })
+            .unwrap();
+
+        let (y, thickness) = items
+            .iter()
+            .find_map(|(pos, item)| match item {
+                FrameItem::Shape(shape, _) => match shape.geometry {
+                    Geometry::Line(_) => Some((pos.y, shape.stroke.as_ref()?.thickness)),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .unwrap();
+
+        let metrics = text.font.metrics().underline;
+        Measured {
+            baseline,
+            y,
+            thickness,
+            font_position: metrics.position.at(text.size),
+            font_thickness: metrics.thickness.at(text.size),
+        }
+    }
+
+    #[test]
+    fn test_underline_defaults_to_font_metrics() {
+        let m = underline("");
+        assert!(m.y.approx_eq(m.baseline - m.font_position));
+        assert!(m.thickness.approx_eq(m.font_thickness));
+    }
+
+    #[test]
+    fn test_underline_offset_and_thickness_override() {
+        let m = underline("offset: 5pt, stroke: 2pt");
+        assert!(m.y.approx_eq(m.baseline + Abs::pt(5.0)));
+        assert!(m.thickness.approx_eq(Abs::pt(2.0)));
+    }
+}
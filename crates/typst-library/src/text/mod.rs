@@ -2,6 +2,8 @@
 
 mod deco;
 mod misc;
+mod num;
+mod quote;
 mod quotes;
 mod raw;
 mod shaping;
@@ -9,6 +11,8 @@ mod shift;
 
 pub use self::deco::*;
 pub use self::misc::*;
+pub use self::num::*;
+pub use self::quote::*;
 pub use self::quotes::*;
 pub use self::raw::*;
 pub use self::shaping::*;
@@ -26,6 +30,7 @@ pub(super) fn define(global: &mut Scope) {
     global.define("text", TextElem::func());
     global.define("linebreak", LinebreakElem::func());
     global.define("smartquote", SmartQuoteElem::func());
+    global.define("quote", QuoteElem::func());
     global.define("strong", StrongElem::func());
     global.define("emph", EmphElem::func());
     global.define("lower", lower_func());
@@ -36,8 +41,10 @@ pub(super) fn define(global: &mut Scope) {
     global.define("underline", UnderlineElem::func());
     global.define("strike", StrikeElem::func());
     global.define("overline", OverlineElem::func());
+    global.define("highlight", HighlightElem::func());
     global.define("raw", RawElem::func());
     global.define("lorem", lorem_func());
+    global.define("num", NumElem::func());
 }
 
 /// Customizes the look and layout of text in a variety of ways.
@@ -140,6 +147,23 @@ pub struct TextElem {
     /// ```
     pub weight: FontWeight,
 
+    /// Whether to synthesize bold and italic styles when the selected font has
+    /// no matching face. Bold text is then emboldened by stroking the glyph
+    /// outlines and italic text is slanted. Typst emits a warning whenever it
+    /// synthesizes a style.
+    ///
+    /// When a font has a real face in the desired style, it is always used
+    /// instead.
+    ///
+    /// ```example
+    /// #set text(font: "PT Sans", synthesize: true)
+    /// Normal \
+    /// *Bold* \
+    /// _Italic_
+    /// ```
+    #[default(false)]
+    pub synthesize: bool,
+
     /// The desired width of the glyphs. Accepts a ratio between `{50%}` and
     /// `{200%}`. When the desired width is not available, Typst selects the
     /// font from the family that is closest in stretch. This will only stretch
@@ -368,6 +392,59 @@ pub struct TextElem {
     #[resolve]
     pub hyphenate: Hyphenate,
 
+    /// Custom hyphenation dictionaries, by language.
+    ///
+    /// Each dictionary lists words with their permitted hyphenation points
+    /// marked by hyphens. Words found in the dictionary of the active
+    /// [text language]($func/text.lang) are hyphenated at these points instead
+    /// of through the bundled patterns. This also makes it possible to
+    /// hyphenate languages for which no patterns are bundled.
+    ///
+    /// ```example
+    /// #set page(width: 60pt)
+    /// #set text(
+    ///   hyphenate: true,
+    ///   hyphenation-dicts: (en: ("Typ-st-ing",)),
+    /// )
+    /// Typsting Typsting
+    /// ```
+    #[fold]
+    pub hyphenation_dicts: HyphenationDicts,
+
+    /// The minimum number of characters in a word before its first
+    /// hyphenation point.
+    ///
+    /// When `{auto}`, the default of the language's hyphenation patterns is
+    /// used.
+    pub hyphenate_before: Smart<usize>,
+
+    /// The minimum number of characters in a word after its last hyphenation
+    /// point.
+    ///
+    /// When `{auto}`, the default of the language's hyphenation patterns is
+    /// used.
+    ///
+    /// ```example
+    /// #set page(width: 60pt)
+    /// #set text(hyphenate: true, hyphenate-after: 4)
+    /// Hyphenation works
+    /// ```
+    pub hyphenate_after: Smart<usize>,
+
+    /// Whether to allow line breaks after punctuation like slashes in long
+    /// unbroken strings such as URLs and paths.
+    ///
+    /// Unlike with hyphenation, no hyphen is inserted at these breaks. This
+    /// also applies to [raw text]($func/raw).
+    ///
+    /// ```example
+    /// #set page(width: 100pt)
+    /// #set text(breakable: true)
+    /// /usr/local/share/fonts/truetype
+    /// ```
+    #[default(false)]
+    pub breakable: bool,
+
     /// Whether to apply kerning.
     ///
     /// When enabled, specific letter pairings move closer together or further
@@ -547,6 +624,14 @@ impl TextElem {
     pub fn packed(text: impl Into<EcoString>) -> Content {
         Self::new(text.into()).pack()
     }
+
+    /// Whether hyphenation was explicitly enabled in the style chain rather
+    /// than just implied by justification.
+    pub fn hyphenate_explicitly_in(styles: StyleChain) -> bool {
+        let hyphenate: Hyphenate =
+            styles.get(Self::func(), "hyphenate", None, Hyphenate::default);
+        matches!(hyphenate.0, Smart::Custom(true))
+    }
 }
 
 impl Construct for TextElem {
@@ -817,6 +902,68 @@ impl Resolve for Hyphenate {
     }
 }
 
+/// Custom hyphenation dictionaries, by language.
+///
+/// Embedders can register dictionaries for additional languages by setting
+/// this property in the library's default styles.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct HyphenationDicts(pub Vec<(Lang, EcoVec<EcoString>)>);
+
+impl HyphenationDicts {
+    /// Whether there is a dictionary for the language.
+    pub fn contains(&self, lang: Lang) -> bool {
+        self.0.iter().any(|(l, _)| *l == lang)
+    }
+
+    /// Find the entry for a word in the language's dictionaries, with its
+    /// hyphenation points marked by hyphens.
+    pub fn get(&self, lang: Lang, word: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .filter(|(l, _)| *l == lang)
+            .flat_map(|(_, words)| words)
+            .find(|entry| {
+                let stripped = entry.chars().filter(|&c| c != '-');
+                stripped
+                    .flat_map(char::to_lowercase)
+                    .eq(word.chars().flat_map(char::to_lowercase))
+            })
+            .map(EcoString::as_str)
+    }
+}
+
+cast! {
+    HyphenationDicts,
+    self => self.0
+        .into_iter()
+        .map(|(lang, words)| {
+            let words: Array = words.into_iter().map(IntoValue::into_value).collect();
+            (lang.as_str().into(), words.into_value())
+        })
+        .collect::<Dict>()
+        .into_value(),
+    values: Dict => Self(values
+        .into_iter()
+        .map(|(k, v)| {
+            let lang = k.parse::<Lang>()?;
+            let words = v.cast::<Array>()?
+                .into_iter()
+                .map(Value::cast)
+                .collect::<StrResult<_>>()?;
+            Ok((lang, words))
+        })
+        .collect::<StrResult<_>>()?),
+}
+
+impl Fold for HyphenationDicts {
+    type Output = Self;
+
+    fn fold(mut self, outer: Self::Output) -> Self::Output {
+        self.0.extend(outer.0);
+        self
+    }
+}
+
 /// A stylistic set in a font.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct StylisticSet(u8);
@@ -0,0 +1,129 @@
+use typst::doc::Lang;
+
+use super::TextElem;
+use crate::compute::calc::Num;
+use crate::prelude::*;
+
+/// Displays a number with separators for thousands and decimals.
+///
+/// By default, the separators are chosen based on the
+/// [text language]($func/text.lang).
+///
+/// ## Example { #example }
+/// ```example
+/// #num(1234567.5) \
+/// #text(lang: "de", num(1234567.5)) \
+/// #num(1234567.5, thousands: " ")
+/// ```
+///
+/// Display: Number
+/// Category: text
+#[element(Show)]
+pub struct NumElem {
+    /// The number to display.
+    #[required]
+    pub value: Num,
+
+    /// The separator between groups of three integer digits.
+    ///
+    /// If set to `{auto}`, the separator is chosen based on the text language.
+    ///
+    /// ```example
+    /// #set num(thousands: "'")
+    /// #num(1000000)
+    /// ```
+    pub thousands: Smart<EcoString>,
+
+    /// The mark between the integer and the fractional digits.
+    ///
+    /// If set to `{auto}`, the mark is chosen based on the text language.
+    ///
+    /// ```example
+    /// #num(3.14, decimal: "·")
+    /// ```
+    pub decimal: Smart<EcoString>,
+}
+
+impl Show for NumElem {
+    #[tracing::instrument(name = "NumElem::show", skip_all)]
+    fn show(&self, _: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
+        let (thousands, decimal) = separators(TextElem::lang_in(styles));
+        let thousands = self.thousands(styles).unwrap_or_else(|| thousands.into());
+        let decimal = self.decimal(styles).unwrap_or_else(|| decimal.into());
+        Ok(TextElem::packed(format_num(self.value(), &thousands, &decimal)))
+    }
+}
+
+/// The default thousands separator and decimal mark for a language.
+fn separators(lang: Lang) -> (&'static str, &'static str) {
+    match lang.as_str() {
+        "da" | "de" | "es" | "id" | "it" | "nl" | "pt" | "sl" | "tr" | "vi" => {
+            (".", ",")
+        }
+        "cs" | "fi" | "fr" | "nb" | "nn" | "pl" | "ru" | "sv" | "uk" => {
+            ("\u{202F}", ",")
+        }
+        _ => (",", "."),
+    }
+}
+
+/// Format a number with the given separators.
+fn format_num(value: Num, thousands: &str, decimal: &str) -> EcoString {
+    let repr = match value {
+        Num::Int(v) => eco_format!("{v}"),
+        Num::Float(v) => eco_format!("{v}"),
+    };
+
+    // Leave infinity and NaN alone.
+    let (sign, digits) = match repr.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", repr.as_str()),
+    };
+
+    if !digits.starts_with(|c: char| c.is_ascii_digit()) {
+        return repr;
+    }
+
+    let (int, frac) = match digits.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (digits, None),
+    };
+
+    let mut formatted = EcoString::from(sign);
+    for (i, c) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i) % 3 == 0 {
+            formatted.push_str(thousands);
+        }
+        formatted.push(c);
+    }
+
+    if let Some(frac) = frac {
+        formatted.push_str(decimal);
+        formatted.push_str(frac);
+    }
+
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_num() {
+        assert_eq!(format_num(Num::Int(0), ",", "."), "0");
+        assert_eq!(format_num(Num::Int(999), ",", "."), "999");
+        assert_eq!(format_num(Num::Int(-1000), ",", "."), "-1,000");
+        assert_eq!(format_num(Num::Float(1234567.5), ".", ","), "1.234.567,5");
+        assert_eq!(format_num(Num::Float(f64::INFINITY), ",", "."), "inf");
+    }
+
+    #[test]
+    fn test_separators() {
+        let lang = |code: &str| code.parse::<Lang>().unwrap();
+        assert_eq!(separators(lang("en")), (",", "."));
+        assert_eq!(separators(lang("de")), (".", ","));
+        assert_eq!(separators(lang("fr")), ("\u{202F}", ","));
+        assert_eq!(separators(lang("uk")), ("\u{202F}", ","));
+    }
+}
@@ -0,0 +1,98 @@
+use super::{SmartQuoteElem, SpaceElem, TextElem};
+use crate::layout::{BlockElem, ParbreakElem};
+use crate::prelude::*;
+
+/// Displays a quote alongside an optional attribution.
+///
+/// ## Example { #example }
+/// ```example
+/// Plato is often credited with
+/// #quote[I know that I know nothing].
+///
+/// #quote(block: true, attribution: [Plato])[
+///   I seem, then, in just this little
+///   thing to be wiser than this man at
+///   any rate, that what I do not know I
+///   do not think I know either.
+/// ]
+/// ```
+///
+/// Display: Quote
+/// Category: text
+#[element(Show)]
+pub struct QuoteElem {
+    /// Whether this is a block quote.
+    ///
+    /// Block quotes are set apart from the surrounding text and indented on
+    /// both sides. Their attribution is displayed below them.
+    ///
+    /// ```example
+    /// An inline quote: #quote[Veni, vidi, vici]
+    ///
+    /// #quote(block: true)[Veni, vidi, vici]
+    /// ```
+    #[default(false)]
+    pub block: bool,
+
+    /// Whether double quotes should be added around this quote.
+    ///
+    /// If set to `{auto}`, inline quotes get quotation marks and block quotes
+    /// don't.
+    ///
+    /// ```example
+    /// #set text(lang: "de")
+    /// #quote(quotes: true, block: true)[Ich bin ein Berliner.]
+    /// ```
+    pub quotes: Smart<bool>,
+
+    /// The attribution of this quote, usually the author or source.
+    ///
+    /// It is displayed after inline quotes and below block quotes.
+    ///
+    /// ```example
+    /// #quote(attribution: [Caesar])[Veni, vidi, vici]
+    /// ```
+    pub attribution: Option<Content>,
+
+    /// How far block quotes are indented on both sides.
+    #[default(Em::new(1.0).into())]
+    pub indent: Length,
+
+    /// The quote.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for QuoteElem {
+    #[tracing::instrument(name = "QuoteElem::show", skip_all)]
+    fn show(&self, _: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
+        let mut realized = self.body();
+        let block = self.block(styles);
+
+        if self.quotes(styles).unwrap_or(!block) {
+            let quote = SmartQuoteElem::new().with_double(true).pack();
+            realized = Content::sequence([quote.clone(), realized, quote]);
+        }
+
+        let attribution = self
+            .attribution(styles)
+            .map(|attribution| TextElem::packed("—\u{a0}") + attribution);
+
+        if block {
+            let mut seq = vec![realized];
+            if let Some(attribution) = attribution {
+                seq.push(ParbreakElem::new().pack());
+                seq.push(attribution.aligned(Axes::with_x(Some(GenAlign::End))));
+            }
+
+            let indent = self.indent(styles).into();
+            let padding = Sides::new(indent, Rel::zero(), indent, Rel::zero());
+            let body = Content::sequence(seq).padded(padding);
+            realized = BlockElem::new().with_body(Some(body)).pack();
+        } else if let Some(attribution) = attribution {
+            realized = Content::sequence([realized, SpaceElem::new().pack(), attribution]);
+        }
+
+        Ok(realized)
+    }
+}
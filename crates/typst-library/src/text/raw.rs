@@ -9,11 +9,12 @@ use typst::diag::FileError;
 use typst::eval::Bytes;
 use typst::syntax::{self, LinkedNode};
 use typst::util::option_eq;
+use unicode_segmentation::UnicodeSegmentation;
 
 use super::{
     FontFamily, FontList, Hyphenate, LinebreakElem, SmartQuoteElem, TextElem, TextSize,
 };
-use crate::layout::BlockElem;
+use crate::layout::{BlockElem, GridElem, ParElem, Sizing, TrackSizings};
 use crate::meta::{Figurable, LocalName};
 use crate::prelude::*;
 
@@ -155,6 +156,50 @@ pub struct RawElem {
     #[default(HorizontalAlign(GenAlign::Start))]
     pub align: HorizontalAlign,
 
+    /// Whether to show line numbers next to the lines of a raw block. Like
+    /// `align`, this option is ignored if this is not a raw block.
+    ///
+    /// ````example
+    /// #set raw(numbering: true)
+    ///
+    /// ```rust
+    /// fn main() {
+    ///     println!("Hello World!");
+    /// }
+    /// ```
+    /// ````
+    #[default(false)]
+    pub numbering: bool,
+
+    /// The number of the first line if line numbers are shown.
+    ///
+    /// ```example
+    /// #raw(
+    ///   "let x = 1;\nlet y = 2;",
+    ///   block: true,
+    ///   lang: "rust",
+    ///   numbering: true,
+    ///   start: 10,
+    /// )
+    /// ```
+    #[default(1)]
+    pub start: usize,
+
+    /// The size for a tab stop in spaces. A tab is replaced with enough spaces
+    /// to align with the next multiple of the size.
+    ///
+    /// ````example
+    /// #set raw(tab-size: 8)
+    /// ```tsv
+    /// Year	Month	Day
+    /// 2000	2	3
+    /// 2001	2	1
+    /// 2002	3	10
+    /// ```
+    /// ````
+    #[default(2)]
+    pub tab_size: usize,
+
     /// One or multiple additional syntax definitions to load. The syntax
     /// definitions should be in the
     /// [`sublime-syntax` file format](https://www.sublimetext.com/docs/syntax.html).
@@ -247,7 +292,11 @@ impl Synthesize for RawElem {
 impl Show for RawElem {
     #[tracing::instrument(name = "RawElem::show", skip_all)]
     fn show(&self, _: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
-        let text = self.text();
+        let mut text = self.text();
+        if text.contains('\t') {
+            text = align_tabs(&text, self.tab_size(styles));
+        }
+
         let lang = self
             .lang(styles)
             .as_ref()
@@ -270,24 +319,31 @@ impl Show for RawElem {
             .map(to_typst)
             .map_or(Color::BLACK, Color::from);
 
-        let mut realized = if matches!(lang.as_deref(), Some("typ" | "typst" | "typc")) {
+        let mut lines = vec![vec![]];
+        if matches!(lang.as_deref(), Some("typ" | "typst" | "typc")) {
             let root = match lang.as_deref() {
                 Some("typc") => syntax::parse_code(&text),
                 _ => syntax::parse(&text),
             };
 
-            let mut seq = vec![];
             let highlighter = synt::Highlighter::new(theme);
             highlight_themed(
                 &LinkedNode::new(&root),
                 vec![],
                 &highlighter,
                 &mut |node, style| {
-                    seq.push(styled(&text[node.range()], foreground.into(), style));
+                    for (i, piece) in text[node.range()].split('\n').enumerate() {
+                        if i != 0 {
+                            lines.push(vec![]);
+                        }
+
+                        if !piece.is_empty() {
+                            let line = lines.last_mut().unwrap();
+                            line.push(styled(piece, foreground.into(), style));
+                        }
+                    }
                 },
             );
-
-            Content::sequence(seq)
         } else if let Some((syntax_set, syntax)) = lang.and_then(|token| {
             SYNTAXES
                 .find_syntax_by_token(&token)
@@ -298,23 +354,51 @@ impl Show for RawElem {
                         .map(|syntax| (&**extra_syntaxes, syntax))
                 })
         }) {
-            let mut seq = vec![];
             let mut highlighter = syntect::easy::HighlightLines::new(syntax, theme);
             for (i, line) in text.lines().enumerate() {
                 if i != 0 {
-                    seq.push(LinebreakElem::new().pack());
+                    lines.push(vec![]);
                 }
 
                 for (style, piece) in
                     highlighter.highlight_line(line, syntax_set).into_iter().flatten()
                 {
-                    seq.push(styled(piece, foreground.into(), style));
+                    let line = lines.last_mut().unwrap();
+                    line.push(styled(piece, foreground.into(), style));
                 }
             }
+        } else {
+            lines = text.lines().map(|line| vec![TextElem::packed(line)]).collect();
+        }
 
-            Content::sequence(seq)
+        let mut realized = if self.block(styles) && self.numbering(styles) {
+            // Lay the numbers and lines out in a grid, so that wrapped lines
+            // keep their number aligned with their first line.
+            let start = self.start(styles);
+            let leading = ParElem::leading_in(styles);
+            let end = Axes::with_x(Some(GenAlign::End));
+            let mut cells = vec![];
+            for (i, line) in lines.into_iter().enumerate() {
+                let number = TextElem::packed(eco_format!("{}", start + i))
+                    .styled(TextElem::set_fill(Color::GRAY.into()));
+                cells.push(number.aligned(end));
+                cells.push(Content::sequence(line));
+            }
+
+            GridElem::new(cells)
+                .with_columns(TrackSizings(vec![Sizing::Auto; 2]))
+                .with_column_gutter(TrackSizings(vec![Em::new(1.0).into()]))
+                .with_row_gutter(TrackSizings(vec![leading.into()]))
+                .pack()
         } else {
-            TextElem::packed(text)
+            let mut seq = vec![];
+            for (i, line) in lines.into_iter().enumerate() {
+                if i != 0 {
+                    seq.push(LinebreakElem::new().pack());
+                }
+                seq.extend(line);
+            }
+            Content::sequence(seq)
         };
 
         if self.block(styles) {
@@ -428,6 +512,36 @@ fn styled(piece: &str, foreground: Paint, style: synt::Style) -> Content {
     body
 }
 
+/// Replace tabs with spaces to align with multiples of `tab_size`.
+fn align_tabs(text: &str, tab_size: usize) -> EcoString {
+    let replacement = " ".repeat(tab_size);
+    let divisor = tab_size.max(1);
+    let amount = text.chars().filter(|&c| c == '\t').count();
+
+    let mut res = EcoString::with_capacity(text.len() - amount + amount * tab_size);
+    let mut column = 0;
+
+    for grapheme in text.graphemes(true) {
+        match grapheme {
+            "\t" => {
+                let required = tab_size - column % divisor;
+                res.push_str(&replacement[..required]);
+                column += required;
+            }
+            "\n" => {
+                res.push_str(grapheme);
+                column = 0;
+            }
+            _ => {
+                res.push_str(grapheme);
+                column += 1;
+            }
+        }
+    }
+
+    res
+}
+
 fn to_typst(synt::Color { r, g, b, a }: synt::Color) -> RgbaColor {
     RgbaColor { r, g, b, a }
 }
@@ -609,3 +723,59 @@ fn item(
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_rust_keyword() {
+        let syntax = SYNTAXES.find_syntax_by_token("rust").unwrap();
+        let mut highlighter = syntect::easy::HighlightLines::new(syntax, &THEME);
+        let pieces = highlighter.highlight_line("fn main() {}", &SYNTAXES).unwrap();
+        let (style, piece) = pieces[0];
+        assert_eq!(piece, "fn");
+        assert_eq!(to_typst(style.foreground), "#d73a49".parse().unwrap());
+    }
+
+    #[test]
+    fn test_highlight_unknown_language() {
+        assert!(SYNTAXES.find_syntax_by_token("not-a-language").is_none());
+    }
+
+    /// The fills of the text runs in a laid out document.
+    fn fills(text: &str) -> Vec<(EcoString, Paint)> {
+        let document = crate::tests::compile(text);
+        crate::tests::items(&document.pages[0])
+            .into_iter()
+            .filter_map(|(_, item)| match item {
+                FrameItem::Text(text) => Some((text.text, text.fill)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_layout_rust_keyword_with_keyword_color() {
+        let fills = fills("```rust\nfn main() {}\n```");
+        let (_, fill) = fills.iter().find(|(text, _)| text.starts_with("fn")).unwrap();
+        assert_eq!(*fill, Paint::from("#d73a49".parse::<RgbaColor>().unwrap()));
+    }
+
+    #[test]
+    fn test_layout_unknown_language_as_plain_text() {
+        let fills = fills("```not-a-language\nfn main() {}\n```");
+        assert!(fills.iter().any(|(text, _)| text.starts_with("fn")));
+        for (_, fill) in fills {
+            assert_eq!(fill, TextElem::fill_in(StyleChain::default()));
+        }
+    }
+
+    #[test]
+    fn test_align_tabs() {
+        assert_eq!(align_tabs("\tx", 4), "    x");
+        assert_eq!(align_tabs("ab\tx", 4), "ab  x");
+        assert_eq!(align_tabs("abcd\tx\n\ty", 4), "abcd    x\n    y");
+        assert_eq!(align_tabs("a\tb", 0), "ab");
+    }
+}
@@ -4,11 +4,12 @@ use std::str::FromStr;
 
 use az::SaturatingAs;
 use rustybuzz::{Feature, Tag, UnicodeBuffer};
-use typst::font::{Font, FontStyle, FontVariant};
+use typst::diag::SourceDiagnostic;
+use typst::font::{Font, FontStyle, FontVariant, FontWeight};
 use typst::util::SliceExt;
 use unicode_script::{Script, UnicodeScript};
 
-use super::{decorate, FontFamily, NumberType, NumberWidth, TextElem};
+use super::{decorate, DecoLine, FontFamily, NumberType, NumberWidth, TextElem};
 use crate::layout::SpanMapper;
 use crate::prelude::*;
 
@@ -214,13 +215,55 @@ enum Side {
 }
 
 impl<'a> ShapedText<'a> {
+    /// Warn about characters that no font covers.
+    ///
+    /// This should be called once per shaped run rather than per built line,
+    /// so that a run broken across lines is only reported once.
+    pub fn warn_uncovered(&self, vt: &mut Vt) {
+        if let Some(first) = self.glyphs.iter().find(|g| g.glyph_id == 0) {
+            let mut warning = uncovered(first.span.0, &self.glyphs);
+            if !TextElem::fallback_in(self.styles) {
+                warning = warning.with_hint("font fallback is disabled");
+            }
+            vt.tracer.warn(warning);
+        }
+    }
+
+    /// Warn about styles that are synthesized because a font lacks a matching
+    /// face.
+    ///
+    /// Like [`warn_uncovered`](Self::warn_uncovered), this should be called
+    /// once per shaped run.
+    pub fn warn_synthesized(&self, vt: &mut Vt) {
+        if !TextElem::synthesize_in(self.styles) {
+            return;
+        }
+
+        let mut fonts: Vec<&Font> = vec![];
+        for glyph in self.glyphs.iter() {
+            if fonts.contains(&&glyph.font) {
+                continue;
+            }
+
+            fonts.push(&glyph.font);
+            if let Some(style) = Synthesis::new(self.variant, &glyph.font).describe() {
+                vt.tracer.warn(warning!(
+                    glyph.span.0,
+                    "synthesizing {} style for font {:?}",
+                    style,
+                    glyph.font.info().family,
+                ));
+            }
+        }
+    }
+
     /// Build the shaped text's frame.
     ///
     /// The `justification` defines how much extra advance width each
     /// [justifiable glyph](ShapedGlyph::is_justifiable) will get.
     pub fn build(
         &self,
-        vt: &Vt,
+        vt: &mut Vt,
         justification_ratio: f64,
         extra_justification: Abs,
     ) -> Frame {
@@ -235,6 +278,7 @@ impl<'a> ShapedText<'a> {
         let lang = TextElem::lang_in(self.styles);
         let decos = TextElem::deco_in(self.styles);
         let fill = TextElem::fill_in(self.styles);
+        let synthesize = TextElem::synthesize_in(self.styles);
 
         for ((font, y_offset), group) in
             self.glyphs.as_ref().group_by_key(|g| (g.font.clone(), g.y_offset))
@@ -245,6 +289,12 @@ impl<'a> ShapedText<'a> {
                 range.end = range.end.max(glyph.range.end);
             }
 
+            let synthesis = if synthesize {
+                Synthesis::new(self.variant, &font)
+            } else {
+                Synthesis::default()
+            };
+
             let pos = Point::new(offset, top + shift - y_offset.at(self.size));
             let glyphs = group
                 .iter()
@@ -289,19 +339,45 @@ impl<'a> ShapedText<'a> {
                 size: self.size,
                 lang,
                 fill: fill.clone(),
+                stroke: synthesis.bold.then(|| Stroke {
+                    paint: fill.clone(),
+                    thickness: Synthesis::EMBOLDEN.at(self.size),
+                    line_join: LineJoin::Round,
+                    ..Stroke::default()
+                }),
                 text: self.text[range.start - self.base..range.end - self.base].into(),
                 glyphs,
             };
 
-            let layer = frame.layer();
             let width = item.width();
+            let (highlights, lines): (Vec<_>, Vec<_>) = decos
+                .iter()
+                .partition(|deco| matches!(deco.line, DecoLine::Highlight { .. }));
+
+            // Apply highlights below the text.
+            for deco in highlights {
+                decorate(&mut frame, deco, &item, shift, pos, width);
+            }
+
+            let layer = frame.layer();
 
             // Apply line decorations.
-            for deco in &decos {
+            for deco in lines {
                 decorate(&mut frame, deco, &item, shift, pos, width);
             }
 
-            frame.insert(layer, pos, FrameItem::Text(item));
+            if synthesis.oblique {
+                // Slant the glyphs around their baseline.
+                let mut slanted = Frame::new(Size::zero());
+                slanted.push(Point::zero(), FrameItem::Text(item));
+                let mut group = GroupItem::new(slanted);
+                group.transform =
+                    Transform::skew(Ratio::new(-Synthesis::SLANT.tan()), Ratio::zero());
+                frame.insert(layer, pos, FrameItem::Group(group));
+            } else {
+                frame.insert(layer, pos, FrameItem::Text(item));
+            }
+
             offset += width;
         }
 
@@ -518,6 +594,45 @@ impl<'a> ShapedText<'a> {
     }
 }
 
+/// Which styles to synthesize for a font that lacks a matching face.
+#[derive(Debug, Default, Copy, Clone)]
+struct Synthesis {
+    /// Whether to embolden the glyphs by stroking their outlines.
+    bold: bool,
+    /// Whether to slant the glyphs.
+    oblique: bool,
+}
+
+impl Synthesis {
+    /// The stroke thickness used to embolden glyphs.
+    const EMBOLDEN: Em = Em::new(0.025);
+
+    /// The angle by which glyphs are slanted.
+    const SLANT: Angle = Angle::raw(0.2094); // 12 degrees
+
+    /// Determine which styles are missing from the `font` that was selected
+    /// for the `variant`.
+    fn new(variant: FontVariant, font: &Font) -> Self {
+        let actual = font.info().variant;
+        Self {
+            bold: variant.weight >= FontWeight::SEMIBOLD
+                && actual.weight < FontWeight::SEMIBOLD,
+            oblique: variant.style != FontStyle::Normal
+                && actual.style == FontStyle::Normal,
+        }
+    }
+
+    /// A human-readable description of the synthesized styles, if any.
+    fn describe(self) -> Option<&'static str> {
+        match (self.bold, self.oblique) {
+            (true, true) => Some("bold italic"),
+            (true, false) => Some("bold"),
+            (false, true) => Some("italic"),
+            (false, false) => None,
+        }
+    }
+}
+
 impl Debug for ShapedText<'_> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         self.text.fmt(f)
@@ -538,6 +653,40 @@ struct ShapingContext<'a, 'v> {
     dir: Dir,
 }
 
+/// Create a warning for characters that no font covers.
+fn uncovered(span: Span, glyphs: &[ShapedGlyph]) -> SourceDiagnostic {
+    // Report the characters in logical order, also for right-to-left text.
+    let mut tofus: Vec<_> = glyphs.iter().filter(|glyph| glyph.glyph_id == 0).collect();
+    tofus.sort_by_key(|glyph| glyph.range.start);
+
+    let mut chars = EcoString::new();
+    let mut scripts = vec![];
+    for glyph in tofus {
+        if !chars.contains(glyph.c) {
+            chars.push(glyph.c);
+        }
+
+        let script = glyph.c.script();
+        if !matches!(script, Script::Common | Script::Inherited | Script::Unknown)
+            && !scripts.contains(&script)
+        {
+            scripts.push(script);
+        }
+    }
+
+    let mut warning = warning!(span, "no font covers the characters {:?}", chars);
+    if !scripts.is_empty() {
+        let names: Vec<_> = scripts.iter().map(|script| script.full_name()).collect();
+        let noun = if names.len() == 1 { "script" } else { "scripts" };
+        warning = warning.with_hint(eco_format!(
+            "the characters belong to the {} {noun}",
+            names.join(", "),
+        ));
+    }
+
+    warning
+}
+
 /// Shape text into [`ShapedText`].
 #[allow(clippy::too_many_arguments)]
 pub fn shape<'a>(
@@ -980,3 +1129,60 @@ fn assert_glyph_ranges_in_order(glyphs: &[ShapedGlyph], dir: Dir) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_is_one_glyph_run() {
+        let document = crate::tests::compile("Hello");
+        let out: Vec<_> = crate::tests::items(&document.pages[0])
+            .into_iter()
+            .filter_map(|(_, item)| match item {
+                FrameItem::Text(text) => Some(text),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].glyphs.len(), 5);
+        assert_eq!(out[0].text, "Hello");
+    }
+
+    #[test]
+    fn test_synthesized_styles() {
+        // The test world only has the regular face.
+        let document = crate::tests::compile(
+            "#set text(synthesize: true)\n\
+             #text(style: \"italic\")[Slanted] #text(weight: \"bold\")[Heavy]",
+        );
+        let page = &document.pages[0];
+
+        let skew = Transform::skew(Ratio::new(-Synthesis::SLANT.tan()), Ratio::zero());
+        let slanted: Vec<_> = crate::tests::groups(page)
+            .into_iter()
+            .filter(|group| group.transform == skew)
+            .flat_map(|group| crate::tests::items(&group.frame))
+            .filter_map(|(_, item)| match item {
+                FrameItem::Text(text) => Some(text.text),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(slanted, ["Slanted"]);
+
+        let texts: Vec<_> = crate::tests::items(page)
+            .into_iter()
+            .filter_map(|(_, item)| match item {
+                FrameItem::Text(text) => Some(text),
+                _ => None,
+            })
+            .collect();
+        let heavy = texts.iter().find(|text| text.text == "Heavy").unwrap();
+        let stroke = heavy.stroke.as_ref().unwrap();
+        assert_eq!(stroke.thickness, Synthesis::EMBOLDEN.at(heavy.size));
+        assert!(texts.iter().filter(|text| text.text != "Heavy").all(|text| {
+            text.stroke.is_none()
+        }));
+    }
+}
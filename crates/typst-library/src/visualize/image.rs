@@ -221,7 +221,7 @@ impl Layout for ImageElem {
 
         // Create a clipping group if only part of the image should be visible.
         if fit == ImageFit::Cover && !target.fits(fitted) {
-            frame.clip();
+            frame.clip(Path::rect(frame.size()));
         }
 
         // Apply metadata.
@@ -0,0 +1,59 @@
+use crate::prelude::*;
+
+/// A repeating pattern with which shapes can be filled or stroked.
+///
+/// The body is laid out once into a tile, which is then repeated horizontally
+/// and vertically across the painted area. Tiles start at the top-left corner
+/// of the painted shape and are clipped at its edges.
+///
+/// ## Example { #example }
+/// ```example
+/// #let dots = pattern(width: 10pt, height: 10pt)[
+///   #place(dx: 3pt, dy: 3pt, circle(radius: 2pt, fill: blue))
+/// ]
+///
+/// #rect(width: 100%, height: 40pt, fill: dots)
+/// ```
+///
+/// The body is laid out with the default styles, so set rules from the
+/// surrounding document don't apply to it.
+///
+/// Display: Pattern
+/// Category: visualize
+#[func]
+pub fn pattern(
+    /// The width of a tile. Defaults to the width of the body.
+    #[named]
+    #[default]
+    width: Smart<Length>,
+    /// The height of a tile. Defaults to the height of the body.
+    #[named]
+    #[default]
+    height: Smart<Length>,
+    /// The content of a single tile.
+    body: Content,
+    /// The callsite span.
+    span: Span,
+    /// The virtual machine.
+    vm: &mut Vm,
+) -> SourceResult<Pattern> {
+    let world = vm.world();
+    let styles = StyleChain::new(&world.library().styles);
+    let size = Axes::new(width, height).map(|s| s.map(|s| s.resolve(styles)));
+    if size.any(|s| matches!(s, Smart::Custom(s) if *s <= Abs::zero())) {
+        bail!(span, "pattern tile must have a non-zero size");
+    }
+
+    let pod = Regions::one(
+        size.map(|s| s.unwrap_or(Abs::inf())),
+        size.map(|s| s.is_custom()),
+    );
+
+    let mut frame = body.measure(&mut vm.vt, styles, pod)?.into_frame();
+    frame.set_size(size.zip(frame.size()).map(|(s, f)| s.unwrap_or(f)));
+    if frame.size().any(|s| *s <= Abs::zero()) {
+        bail!(span, "pattern tile must have a non-zero size");
+    }
+
+    Ok(Pattern::new(frame))
+}
@@ -124,6 +124,17 @@ pub struct RectElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// Whether to clip the content inside the rectangle to its outline,
+    /// including rounded corners.
+    ///
+    /// ```example
+    /// #rect(radius: 8pt, inset: 0pt, clip: true)[
+    ///   #image("tiger.jpg", width: 80pt)
+    /// ]
+    /// ```
+    #[default(false)]
+    pub clip: bool,
+
     /// The content to place into the rectangle.
     ///
     /// When this is omitted, the rectangle takes on a default size of at most
@@ -152,6 +163,7 @@ impl Layout for RectElem {
             self.inset(styles),
             self.outset(styles),
             self.radius(styles),
+            self.clip(styles),
             self.span(),
         )
     }
@@ -232,6 +244,11 @@ pub struct SquareElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// Whether to clip the content inside the square to its outline. See the
+    /// [rectangle's documentation]($func/rect.clip) for more details.
+    #[default(false)]
+    pub clip: bool,
+
     /// The content to place into the square. The square expands to fit this
     /// content, keeping the 1-1 aspect ratio.
     ///
@@ -261,6 +278,7 @@ impl Layout for SquareElem {
             self.inset(styles),
             self.outset(styles),
             self.radius(styles),
+            self.clip(styles),
             self.span(),
         )
     }
@@ -314,6 +332,11 @@ pub struct EllipseElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// Whether to clip the content inside the ellipse to its outline. See the
+    /// [rectangle's documentation]($func/rect.clip) for more details.
+    #[default(false)]
+    pub clip: bool,
+
     /// The content to place into the ellipse.
     ///
     /// When this is omitted, the ellipse takes on a default size of at most
@@ -342,6 +365,7 @@ impl Layout for EllipseElem {
             self.inset(styles),
             self.outset(styles),
             Corners::splat(Rel::zero()),
+            self.clip(styles),
             self.span(),
         )
     }
@@ -422,6 +446,17 @@ pub struct CircleElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// Whether to clip the content inside the circle to its outline. This is
+    /// useful for circular crops of images.
+    ///
+    /// ```example
+    /// #circle(radius: 25pt, inset: 0pt, clip: true)[
+    ///   #image("tiger.jpg", height: 50pt)
+    /// ]
+    /// ```
+    #[default(false)]
+    pub clip: bool,
+
     /// The content to place into the circle. The circle expands to fit this
     /// content, keeping the 1-1 aspect ratio.
     #[positional]
@@ -448,6 +483,7 @@ impl Layout for CircleElem {
             self.inset(styles),
             self.outset(styles),
             Corners::splat(Rel::zero()),
+            self.clip(styles),
             self.span(),
         )
     }
@@ -468,6 +504,7 @@ fn layout(
     mut inset: Sides<Rel<Abs>>,
     outset: Sides<Rel<Abs>>,
     radius: Corners<Rel<Abs>>,
+    clip: bool,
     span: Span,
 ) -> SourceResult<Fragment> {
     let resolved = sizing
@@ -515,6 +552,17 @@ fn layout(
         frame = Frame::new(size);
     }
 
+    // Clip the contents to the outline of the shape.
+    if clip {
+        let size = frame.size();
+        frame.clip(if kind.is_round() {
+            ellipse_path(size)
+        } else {
+            let radius = radius.map(|side| side.relative_to(size.x.min(size.y) / 2.0));
+            rounded_rect_path(size, radius)
+        });
+    }
+
     // Prepare stroke.
     let stroke = match stroke {
         Smart::Auto if fill.is_none() => Sides::splat(Some(Stroke::default())),
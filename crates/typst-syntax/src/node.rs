@@ -922,4 +922,16 @@ mod tests {
         assert_eq!(leaf.text(), " ");
         assert_eq!(next.text(), "10");
     }
+
+    #[test]
+    fn test_syntax_node_debug() {
+        let root = crate::parse("#box(width: 10pt)[hi]");
+        let dump = format!("{root:?}");
+        assert!(dump.starts_with("Markup: 21 [Hashtag: \"#\", FuncCall: 20 ["));
+        assert!(dump.contains("Ident: \"box\""));
+        assert!(dump.contains("Named: 11 [Ident: \"width\", Colon: \":\""));
+        assert!(dump.contains("Numeric: \"10pt\""));
+        assert!(dump.contains("ContentBlock: 4 [LeftBracket: \"[\", Markup: 2"));
+        assert!(dump.contains("Text: \"hi\""));
+    }
 }
@@ -7,12 +7,14 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use ecow::EcoString;
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
 
-use crate::eval::{cast, dict, Dict, Value};
+use crate::eval::{cast, dict, Dict, IntoValue, Value};
 use crate::font::Font;
 use crate::geom::{
     self, rounded_rect, Abs, Align, Axes, Color, Corners, Dir, Em, Geometry, Length,
-    Numeric, Paint, Point, Rel, RgbaColor, Shape, Sides, Size, Stroke, Transform,
+    Numeric, Paint, Point, Ratio, Rel, RgbaColor, Shape, Sides, Size, Stroke, Transform,
 };
 use crate::image::Image;
 use crate::model::{Content, Location, MetaElem, StyleChain};
@@ -29,6 +31,41 @@ pub struct Document {
     pub author: Vec<EcoString>,
 }
 
+impl Document {
+    /// Arrange the pages on sheets of the given size in a grid with the given
+    /// number of columns and rows, e.g. for handouts.
+    ///
+    /// Each page is scaled to fit into its cell. When the pages don't fill the
+    /// last sheet, its remaining cells stay empty.
+    pub fn n_up(&self, sheet: Size, grid: Axes<usize>) -> Self {
+        let grid = grid.map(|count| count.max(1));
+        let cell = Size::new(sheet.x / grid.x as f64, sheet.y / grid.y as f64);
+        let pages = self
+            .pages
+            .chunks(grid.x * grid.y)
+            .map(|chunk| {
+                let mut frame = Frame::new(sheet);
+                for (i, page) in chunk.iter().enumerate() {
+                    let mut page = page.clone();
+                    page.fit(cell);
+                    let pos = Point::new(
+                        cell.x * (i % grid.x) as f64,
+                        cell.y * (i / grid.x) as f64,
+                    );
+                    frame.push(pos, FrameItem::Group(GroupItem::new(page)));
+                }
+                frame
+            })
+            .collect();
+
+        Self {
+            pages,
+            title: self.title.clone(),
+            author: self.author.clone(),
+        }
+    }
+}
+
 /// A finished layout with items at fixed positions.
 #[derive(Default, Clone, Hash)]
 pub struct Frame {
@@ -254,6 +291,16 @@ impl Frame {
         }
     }
 
+    /// Mark the contents of the frame as having a role in the document's
+    /// logical structure.
+    pub fn tag(&mut self, role: Role) {
+        if !self.is_empty() {
+            let start = FrameItem::Meta(Meta::Tag(Tag::Start(role)), Size::zero());
+            self.prepend(Point::zero(), start);
+            self.push(Point::zero(), FrameItem::Meta(Meta::Tag(Tag::End), Size::zero()));
+        }
+    }
+
     /// Attach the metadata from this style chain to the frame.
     pub fn meta(&mut self, styles: StyleChain, force: bool) {
         if force || !self.is_empty() {
@@ -313,10 +360,30 @@ impl Frame {
         }
     }
 
-    /// Clip the contents of a frame to its size.
-    pub fn clip(&mut self) {
+    /// Uniformly scale the contents of the frame to fit into the target size,
+    /// centering them on the axis with leftover space.
+    pub fn fit(&mut self, target: Size) {
+        let scale = (target.x / self.width()).min(target.y / self.height());
+        if !scale.is_finite() || scale <= 0.0 {
+            self.resize(target, Align::CENTER_HORIZON);
+            return;
+        }
+
+        let offset = (target - self.size * scale) / 2.0;
+        self.transform(Transform::scale(Ratio::new(scale), Ratio::new(scale)));
+        self.size = target;
+        self.translate(offset.to_point());
+    }
+
+    /// Clip the contents of a frame to a path.
+    ///
+    /// To clip to the frame's bounds, pass [`Path::rect`] of the frame's size.
+    /// Nested clip paths intersect.
+    ///
+    /// [`Path::rect`]: geom::Path::rect
+    pub fn clip(&mut self, clip_path: geom::Path) {
         if !self.is_empty() {
-            self.group(|g| g.clips = true);
+            self.group(|g| g.clip_path = Some(clip_path));
         }
     }
 
@@ -404,6 +471,180 @@ impl Debug for Frame {
     }
 }
 
+/// Serializes the frame tree with all lengths in points, for golden tests
+/// and external tooling.
+impl Serialize for Frame {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let items: Vec<_> =
+            self.items().map(|(pos, item)| PositionedItem(*pos, item)).collect();
+        let mut map = serializer.serialize_map(Some(4))?;
+        map.serialize_entry("width", &self.width().to_pt())?;
+        map.serialize_entry("height", &self.height().to_pt())?;
+        map.serialize_entry("baseline", &self.baseline().to_pt())?;
+        map.serialize_entry("items", &items)?;
+        map.end()
+    }
+}
+
+/// A frame item at its position in the parent frame.
+struct PositionedItem<'a>(Point, &'a FrameItem);
+
+impl Serialize for PositionedItem<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Self(pos, item) = *self;
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("x", &pos.x.to_pt())?;
+        map.serialize_entry("y", &pos.y.to_pt())?;
+        match item {
+            FrameItem::Group(group) => {
+                let Transform { sx, ky, kx, sy, tx, ty } = group.transform;
+                let transform =
+                    [sx.get(), ky.get(), kx.get(), sy.get(), tx.to_pt(), ty.to_pt()];
+                map.serialize_entry("kind", "group")?;
+                map.serialize_entry("transform", &transform)?;
+                map.serialize_entry("clipped", &group.clip_path.is_some())?;
+                map.serialize_entry("frame", &group.frame)?;
+            }
+            FrameItem::Text(text) => {
+                let glyphs: Vec<_> = text.glyphs.iter().map(|glyph| glyph.id).collect();
+                map.serialize_entry("kind", "text")?;
+                map.serialize_entry("text", text.text.as_str())?;
+                map.serialize_entry("font", text.font.info().family.as_str())?;
+                map.serialize_entry("size", &text.size.to_pt())?;
+                map.serialize_entry("width", &text.width().to_pt())?;
+                map.serialize_entry("glyphs", &glyphs)?;
+                map.serialize_entry("fill", &PaintEntry(&text.fill))?;
+                map.serialize_entry("stroke", &text.stroke.as_ref().map(StrokeEntry))?;
+            }
+            FrameItem::Shape(shape, _) => {
+                map.serialize_entry("kind", "shape")?;
+                match &shape.geometry {
+                    Geometry::Line(target) => {
+                        map.serialize_entry("geometry", "line")?;
+                        let target = [target.x.to_pt(), target.y.to_pt()];
+                        map.serialize_entry("target", &target)?;
+                    }
+                    Geometry::Rect(size) => {
+                        map.serialize_entry("geometry", "rect")?;
+                        map.serialize_entry("width", &size.x.to_pt())?;
+                        map.serialize_entry("height", &size.y.to_pt())?;
+                    }
+                    Geometry::Path(path) => {
+                        let items: Vec<_> = path.0.iter().map(PathEntry).collect();
+                        map.serialize_entry("geometry", "path")?;
+                        map.serialize_entry("path", &items)?;
+                    }
+                }
+                map.serialize_entry("fill", &shape.fill.as_ref().map(PaintEntry))?;
+                map.serialize_entry("stroke", &shape.stroke.as_ref().map(StrokeEntry))?;
+            }
+            FrameItem::Image(image, size, _) => {
+                map.serialize_entry("kind", "image")?;
+                map.serialize_entry("format", &image.format().into_value())?;
+                map.serialize_entry("width", &size.x.to_pt())?;
+                map.serialize_entry("height", &size.y.to_pt())?;
+            }
+            FrameItem::Meta(meta, size) => {
+                map.serialize_entry("kind", "meta")?;
+                match meta {
+                    Meta::Link(..) => map.serialize_entry("meta", "link")?,
+                    Meta::Elem(elem) => map.serialize_entry("meta", elem.func().name())?,
+                    Meta::PageNumbering(_) => map.serialize_entry("meta", "numbering")?,
+                    Meta::Hide => map.serialize_entry("meta", "hide")?,
+                    Meta::Tag(_) => map.serialize_entry("meta", "tag")?,
+                }
+                map.serialize_entry("width", &size.x.to_pt())?;
+                map.serialize_entry("height", &size.y.to_pt())?;
+            }
+        }
+        map.end()
+    }
+}
+
+/// A paint, tagged with its kind.
+struct PaintEntry<'a>(&'a Paint);
+
+impl Serialize for PaintEntry<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        match self.0 {
+            Paint::Solid(color) => {
+                map.serialize_entry("kind", "color")?;
+                map.serialize_entry("color", &color.into_value())?;
+            }
+            Paint::Pattern(pattern) => {
+                map.serialize_entry("kind", "pattern")?;
+                map.serialize_entry("tile", pattern.tile())?;
+            }
+        }
+        map.end()
+    }
+}
+
+/// A stroke with all lengths in points.
+struct StrokeEntry<'a>(&'a Stroke);
+
+impl Serialize for StrokeEntry<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let stroke = self.0;
+        let dash = stroke.dash_pattern.as_ref().map(|pattern| {
+            let array: Vec<_> = pattern.array.iter().map(|len| len.to_pt()).collect();
+            dict! { "array" => array, "phase" => pattern.phase.to_pt() }
+        });
+        let mut map = serializer.serialize_map(Some(6))?;
+        map.serialize_entry("paint", &PaintEntry(&stroke.paint))?;
+        map.serialize_entry("thickness", &stroke.thickness.to_pt())?;
+        map.serialize_entry("cap", &stroke.line_cap.into_value())?;
+        map.serialize_entry("join", &stroke.line_join.into_value())?;
+        map.serialize_entry("dash", &dash)?;
+        map.serialize_entry("miter-limit", &stroke.miter_limit.get())?;
+        map.end()
+    }
+}
+
+/// A path item, tagged with its operation.
+struct PathEntry<'a>(&'a geom::PathItem);
+
+impl Serialize for PathEntry<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let point = |p: &Point| [p.x.to_pt(), p.y.to_pt()];
+        let mut map = serializer.serialize_map(None)?;
+        match self.0 {
+            geom::PathItem::MoveTo(p) => {
+                map.serialize_entry("op", "move")?;
+                map.serialize_entry("to", &point(p))?;
+            }
+            geom::PathItem::LineTo(p) => {
+                map.serialize_entry("op", "line")?;
+                map.serialize_entry("to", &point(p))?;
+            }
+            geom::PathItem::CubicTo(p1, p2, p3) => {
+                map.serialize_entry("op", "cubic")?;
+                map.serialize_entry("control1", &point(p1))?;
+                map.serialize_entry("control2", &point(p2))?;
+                map.serialize_entry("to", &point(p3))?;
+            }
+            geom::PathItem::ClosePath => map.serialize_entry("op", "close")?,
+        }
+        map.end()
+    }
+}
+
 /// The building block frames are composed of.
 #[derive(Clone, Hash)]
 pub enum FrameItem {
@@ -438,8 +679,8 @@ pub struct GroupItem {
     pub frame: Frame,
     /// A transformation to apply to the group.
     pub transform: Transform,
-    /// Whether the frame should be a clipping boundary.
-    pub clips: bool,
+    /// A path outside of which the group's contents are not visible.
+    pub clip_path: Option<geom::Path>,
 }
 
 impl GroupItem {
@@ -448,7 +689,7 @@ impl GroupItem {
         Self {
             frame,
             transform: Transform::identity(),
-            clips: false,
+            clip_path: None,
         }
     }
 }
@@ -469,6 +710,9 @@ pub struct TextItem {
     pub size: Abs,
     /// Glyph color.
     pub fill: Paint,
+    /// An optional outline stroke around the glyphs. This is used to
+    /// synthesize a bold variant for fonts which lack one.
+    pub stroke: Option<Stroke>,
     /// The natural language of the text.
     pub lang: Lang,
     /// The item's plain text.
@@ -662,8 +906,9 @@ cast! {
 /// Meta information that isn't visible or renderable.
 #[derive(Clone, PartialEq, Hash)]
 pub enum Meta {
-    /// An internal or external link to a destination.
-    Link(Destination),
+    /// An internal or external link to a destination, with an optional text
+    /// describing it.
+    Link(Destination, Option<EcoString>),
     /// An identifiable element that produces something within the area this
     /// metadata is attached to.
     Elem(Content),
@@ -673,6 +918,8 @@ pub enum Meta {
     /// in the final frames as it is removed alongside the content that should
     /// be hidden.
     Hide,
+    /// Delimits content with a role in the document's logical structure.
+    Tag(Tag),
 }
 
 cast! {
@@ -682,14 +929,53 @@ cast! {
 impl Debug for Meta {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
-            Self::Link(dest) => write!(f, "Link({dest:?})"),
+            Self::Link(dest, _) => write!(f, "Link({dest:?})"),
             Self::Elem(content) => write!(f, "Elem({:?})", content.func()),
             Self::PageNumbering(value) => write!(f, "PageNumbering({value:?})"),
             Self::Hide => f.pad("Hide"),
+            Self::Tag(tag) => write!(f, "Tag({tag:?})"),
         }
     }
 }
 
+/// Delimits content with a role in the document's logical structure.
+///
+/// Tags are placed into frames in pairs, so that they survive the inlining
+/// of frames into their parents.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Tag {
+    /// The start of content with the given role.
+    Start(Role),
+    /// The end of the most recently started content.
+    End,
+}
+
+/// The role of content in the document's logical structure.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Role {
+    /// A paragraph.
+    Paragraph,
+    /// A heading of the given level.
+    Heading(NonZeroUsize),
+    /// A list of any kind.
+    List,
+    /// An item of a list.
+    ListItem,
+    /// A table.
+    Table,
+    /// A figure, including its caption.
+    Figure,
+    /// A note like a footnote.
+    Note,
+    /// Page furniture like headers and footers, which is not part of the
+    /// document's content.
+    Artifact,
+}
+
+cast! {
+    type Role: "role",
+}
+
 /// A link destination.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum Destination {
@@ -756,6 +1042,133 @@ mod tests {
         assert!(!option_eq(region, "AB"));
     }
 
+    #[test]
+    fn test_frame_fill_with_outset() {
+        let mut frame = Frame::new(Size::new(Abs::pt(20.0), Abs::pt(10.0)));
+        let outset = Sides::new(2.0, 1.0, 3.0, 4.0).map(|v| Abs::pt(v).into());
+        let radius = Corners::splat(geom::Ratio::new(0.5).into());
+        frame.fill_and_stroke(
+            Some(Color::BLACK.into()),
+            Sides::splat(None),
+            outset,
+            radius,
+            Span::detached(),
+        );
+
+        // The painted area grows, but the frame keeps its size.
+        assert_eq!(frame.size(), Size::new(Abs::pt(20.0), Abs::pt(10.0)));
+        let Some((pos, FrameItem::Shape(shape, _))) = frame.items().next() else {
+            panic!("expected a shape");
+        };
+        let size = Size::new(Abs::pt(25.0), Abs::pt(15.0));
+        let radius = Corners::splat(Abs::pt(3.75));
+        assert_eq!(*pos, Point::new(Abs::pt(-2.0), Abs::pt(-1.0)));
+        assert_eq!(shape.geometry, Geometry::Path(geom::rounded_rect_path(size, radius)));
+    }
+
+    #[test]
+    fn test_frame_fit_a4_to_letter() {
+        let a4 = Size::new(Abs::mm(210.0), Abs::mm(297.0));
+        let letter = Size::new(Abs::inches(8.5), Abs::inches(11.0));
+        let mut frame = Frame::new(a4);
+        frame.push(Point::zero(), FrameItem::Meta(Meta::Hide, a4));
+        frame.fit(letter);
+
+        // The height is the limiting axis, so the page is centered horizontally.
+        let scale = letter.y / a4.y;
+        assert_eq!(frame.size(), letter);
+        let Some((pos, FrameItem::Group(group))) = frame.items().next() else {
+            panic!("expected a group");
+        };
+        assert!((scale - 0.9407).abs() < 1e-4);
+        assert!(pos.x.approx_eq((letter.x - a4.x * scale) / 2.0));
+        assert!(pos.y.approx_eq(Abs::zero()));
+        let ratio = Ratio::new(scale);
+        assert_eq!(group.transform, Transform::scale(ratio, ratio));
+    }
+
+    #[test]
+    fn test_document_n_up() {
+        let a5 = Size::new(Abs::mm(148.5), Abs::mm(210.0));
+        let sheet = Size::new(Abs::mm(297.0), Abs::mm(210.0));
+        let document = Document {
+            pages: vec![Frame::new(a5); 3],
+            ..Default::default()
+        };
+
+        // Two pages on the first sheet and one with an empty cell on the second.
+        let sheets = document.n_up(sheet, Axes::new(2, 1));
+        assert_eq!(sheets.pages.len(), 2);
+        let positions = |frame: &Frame| -> Vec<Point> {
+            frame.items().map(|(pos, _)| *pos).collect()
+        };
+
+        assert_eq!(sheets.pages[0].size(), sheet);
+        assert_eq!(
+            positions(&sheets.pages[0]),
+            [Point::zero(), Point::with_x(sheet.x / 2.0)]
+        );
+        assert_eq!(positions(&sheets.pages[1]), [Point::zero()]);
+    }
+
+    #[test]
+    fn test_frame_serialize() {
+        let mut frame = Frame::new(Size::new(Abs::pt(20.0), Abs::pt(10.0)));
+        let rect = Geometry::Rect(Size::splat(Abs::pt(5.0))).filled(Color::BLACK.into());
+        let pos = Point::new(Abs::pt(2.0), Abs::pt(3.0));
+        frame.push(pos, FrameItem::Shape(rect, Span::detached()));
+
+        let json = serde_json::to_value(&frame).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "width": 20.0,
+                "height": 10.0,
+                "baseline": 10.0,
+                "items": [{
+                    "x": 2.0,
+                    "y": 3.0,
+                    "kind": "shape",
+                    "geometry": "rect",
+                    "width": 5.0,
+                    "height": 5.0,
+                    "fill": { "kind": "color", "color": "rgb(\"#000000\")" },
+                    "stroke": null,
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn test_path_serialize() {
+        let mut path = geom::Path::new();
+        path.move_to(Point::zero());
+        path.cubic_to(
+            Point::with_x(Abs::pt(1.0)),
+            Point::with_y(Abs::pt(2.0)),
+            Point::splat(Abs::pt(3.0)),
+        );
+        path.close_path();
+
+        let json = serde_json::to_value(PathEntry(&path.0[1])).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "op": "cubic",
+                "control1": [1.0, 0.0],
+                "control2": [0.0, 2.0],
+                "to": [3.0, 3.0],
+            })
+        );
+
+        let ops: Vec<_> = path
+            .0
+            .iter()
+            .map(|item| serde_json::to_value(PathEntry(item)).unwrap()["op"].clone())
+            .collect();
+        assert_eq!(ops, ["move", "cubic", "close"]);
+    }
+
     #[test]
     fn test_document_is_send() {
         fn ensure_send<T: Send>() {}
@@ -673,4 +673,29 @@ mod tests {
         test(dict!["one" => 1], "(one: 1)");
         test(dict!["two" => false, "one" => 1], "(two: false, one: 1)");
     }
+
+    #[track_caller]
+    fn roundtrip<T>(value: T)
+    where
+        T: IntoValue + FromValue + PartialEq + Debug + Clone,
+    {
+        assert_eq!(value.clone().into_value().cast::<T>(), Ok(value));
+    }
+
+    #[test]
+    fn test_value_cast() {
+        // Round-tripping.
+        roundtrip(true);
+        roundtrip(12i64);
+        roundtrip(3.24);
+        roundtrip(Length::from(Abs::pt(5.5)));
+        roundtrip(Color::Rgba(RgbaColor::new(1, 1, 1, 0xff)));
+        roundtrip(Str::from("hello"));
+        roundtrip(array![1, "two"]);
+        roundtrip(dict!["one" => 1]);
+
+        // Coercion.
+        assert_eq!(Value::Int(3).cast::<f64>(), Ok(3.0));
+        assert!(Value::Float(3.0).cast::<i64>().is_err());
+    }
 }
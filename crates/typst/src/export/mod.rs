@@ -4,6 +4,6 @@ mod pdf;
 mod render;
 mod svg;
 
-pub use self::pdf::pdf;
+pub use self::pdf::{pdf, pdf_a, pdf_print, pdf_tagged};
 pub use self::render::{render, render_merged};
 pub use self::svg::{svg, svg_merged};
@@ -0,0 +1,123 @@
+//! A minimal sRGB ICC profile for PDF output intents.
+
+/// The D50 white point of the profile connection space.
+const D50: [f64; 3] = [0.9642, 1.0, 0.8249];
+
+/// The sRGB primaries, chromatically adapted to D50.
+const PRIMARIES: [[f64; 3]; 3] = [
+    [0.4360747, 0.2225045, 0.0139322],
+    [0.3850649, 0.7168786, 0.0971045],
+    [0.1430804, 0.0606169, 0.7141733],
+];
+
+/// The number of points at which the transfer function is sampled.
+const TRC_POINTS: usize = 1024;
+
+/// The description of the profile.
+const DESCRIPTION: &str = "sRGB IEC61966-2.1";
+
+/// Build a version 2 matrix/TRC display profile with the sRGB primaries and
+/// transfer function.
+pub fn srgb() -> Vec<u8> {
+    let xyz = |[x, y, z]: [f64; 3]| {
+        let mut data = b"XYZ \0\0\0\0".to_vec();
+        for v in [x, y, z] {
+            data.extend(s15_fixed16(v));
+        }
+        data
+    };
+
+    // The piecewise sRGB transfer function, sampled into a table.
+    let mut trc = b"curv\0\0\0\0".to_vec();
+    trc.extend((TRC_POINTS as u32).to_be_bytes());
+    for i in 0..TRC_POINTS {
+        let v = i as f64 / (TRC_POINTS - 1) as f64;
+        let linear =
+            if v <= 0.04045 { v / 12.92 } else { ((v + 0.055) / 1.055).powf(2.4) };
+        trc.extend(((linear * 65535.0).round() as u16).to_be_bytes());
+    }
+
+    let mut desc = b"desc\0\0\0\0".to_vec();
+    desc.extend((DESCRIPTION.len() as u32 + 1).to_be_bytes());
+    desc.extend(DESCRIPTION.as_bytes());
+    desc.extend([0; 1 + 4 + 4 + 2 + 1 + 67]);
+
+    let tags: [(&[u8; 4], Vec<u8>); 9] = [
+        (b"desc", desc),
+        (b"cprt", b"text\0\0\0\0No copyright, use freely\0".to_vec()),
+        (b"wtpt", xyz(D50)),
+        (b"rXYZ", xyz(PRIMARIES[0])),
+        (b"gXYZ", xyz(PRIMARIES[1])),
+        (b"bXYZ", xyz(PRIMARIES[2])),
+        (b"rTRC", trc.clone()),
+        (b"gTRC", trc.clone()),
+        (b"bTRC", trc),
+    ];
+
+    // Lay out the tag table and the 4-byte aligned tag data after it.
+    let mut table = (tags.len() as u32).to_be_bytes().to_vec();
+    let mut data = vec![];
+    let start = 128 + 4 + 12 * tags.len();
+    for (signature, element) in &tags {
+        table.extend(*signature);
+        table.extend(((start + data.len()) as u32).to_be_bytes());
+        table.extend((element.len() as u32).to_be_bytes());
+        data.extend(element);
+        data.resize((data.len() + 3) / 4 * 4, 0);
+    }
+
+    let size = start + data.len();
+    let mut profile = Vec::with_capacity(size);
+    profile.extend((size as u32).to_be_bytes());
+    profile.extend([0; 4]); // Preferred CMM.
+    profile.extend([2, 0x10, 0, 0]); // Version 2.1.
+    profile.extend(b"mntrRGB XYZ ");
+    profile.extend([0; 12]); // Creation date.
+    profile.extend(b"acsp");
+    profile.extend([0; 24]); // Platform, flags, manufacturer, model, attributes.
+    profile.extend([0; 4]); // Perceptual rendering intent.
+    for v in D50 {
+        profile.extend(s15_fixed16(v));
+    }
+    profile.extend([0; 4 + 16 + 28]); // Creator, ID, and reserved bytes.
+    profile.extend(table);
+    profile.extend(data);
+    profile
+}
+
+/// Encode a number as a signed 15.16 fixed point number.
+fn s15_fixed16(v: f64) -> [u8; 4] {
+    ((v * 65536.0).round() as i32).to_be_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_srgb_profile_layout() {
+        let profile = srgb();
+        assert_eq!(profile.len() % 4, 0);
+        assert_eq!(&profile[..4], (profile.len() as u32).to_be_bytes());
+        assert_eq!(&profile[36..40], b"acsp");
+        assert_eq!(&profile[128..132], 9u32.to_be_bytes());
+    }
+
+    #[test]
+    fn test_srgb_transfer_function() {
+        let profile = srgb();
+        let start = profile.windows(4).position(|window| window == b"curv").unwrap();
+        let count = &profile[start + 8..start + 12];
+        assert_eq!(count, (TRC_POINTS as u32).to_be_bytes());
+
+        // The midpoint of the encoded range is about 21.5% of the linear light.
+        let sample = |i: usize| {
+            let at = start + 12 + 2 * i;
+            u16::from_be_bytes([profile[at], profile[at + 1]])
+        };
+        assert_eq!(sample(0), 0);
+        assert_eq!(sample(TRC_POINTS - 1), u16::MAX);
+        let mid = sample(TRC_POINTS / 2) as f64 / 65535.0;
+        assert!((mid - 0.2145).abs() < 0.001);
+    }
+}
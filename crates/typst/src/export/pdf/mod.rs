@@ -2,35 +2,104 @@
 
 mod external_graphics_state;
 mod font;
+mod icc;
 mod image;
 mod outline;
 mod page;
+mod pattern;
+mod tags;
 
 use std::cmp::Eq;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::hash::Hash;
 
 use ecow::EcoString;
 use pdf_writer::types::Direction;
-use pdf_writer::{Finish, Name, PdfWriter, Ref, TextStr};
+use pdf_writer::{Filter, Finish, Name, Null, PdfWriter, Ref, TextStr};
 use xmp_writer::{LangId, RenditionClass, XmpWriter};
 
 use self::page::Page;
-use crate::doc::{Document, Lang};
+use self::tags::StructElem;
+use crate::diag::{bail, StrResult};
+use crate::doc::{Document, Frame, FrameItem, Lang};
 use crate::font::Font;
 use crate::geom::{Abs, Dir, Em};
 use crate::image::Image;
-use crate::model::Introspector;
+use crate::model::{Introspector, Location};
+use crate::util::hash128;
 
 use external_graphics_state::ExternalGraphicsState;
+use pattern::PdfPattern;
 
 /// Export a document into a PDF file.
 ///
 /// Returns the raw bytes making up the PDF file.
 #[tracing::instrument(skip_all)]
 pub fn pdf(document: &Document) -> Vec<u8> {
-    let mut ctx = PdfContext::new(document);
+    write(PdfContext::new(document, None))
+}
+
+/// Export a document into a PDF file for print.
+///
+/// Each page is surrounded by a bleed area of the given size, which is visible
+/// in the PDF's bleed box, and by crop marks at the corners of the trimmed
+/// page. The page's fill is extended into the bleed area, as is any content
+/// that reaches beyond the page's edges.
+#[tracing::instrument(skip_all)]
+pub fn pdf_print(document: &Document, bleed: Abs) -> Vec<u8> {
+    write(PdfContext::new(document, Some(bleed)))
+}
+
+/// Export a document into a tagged PDF file.
+///
+/// Tagged PDFs additionally contain a structure tree with the document's
+/// headings, paragraphs, lists, tables, figures and notes in reading order, so
+/// that assistive technology like screen readers can make sense of the
+/// document. Page furniture like headers and footers is marked as such.
+#[tracing::instrument(skip_all)]
+pub fn pdf_tagged(document: &Document) -> Vec<u8> {
+    let mut ctx = PdfContext::new(document, None);
+    ctx.tagged = true;
+    write(ctx)
+}
+
+/// Export a document into a PDF/A-2b conforming file for archival.
+///
+/// In addition to a regular export, this embeds an sRGB output intent,
+/// identifies the file as PDF/A in its metadata and gives it a file
+/// identifier. Links are flagged to be printed.
+///
+/// Fails if the document uses something that PDF/A forbids: Characters that
+/// no font covers, or pages that are smaller than 3pt or larger than 14400pt
+/// in either direction.
+#[tracing::instrument(skip_all)]
+pub fn pdf_a(document: &Document) -> StrResult<Vec<u8>> {
+    for (i, frame) in document.pages.iter().enumerate() {
+        let page = i + 1;
+        let size = frame.size();
+        let range = Abs::pt(3.0)..=Abs::pt(14400.0);
+        if !range.contains(&size.x) || !range.contains(&size.y) {
+            bail!("page {page} is too small or too large for PDF/A");
+        }
+
+        if has_notdef(frame) {
+            bail!("page {page} contains characters that no font covers");
+        }
+    }
+
+    let mut ctx = PdfContext::new(document, None);
+    ctx.archival = true;
+    let id = hash128(document);
+    let mut bytes = write(ctx);
+    write_file_id(&mut bytes, id);
+    Ok(bytes)
+}
+
+/// Write all parts of the document into the PDF file.
+fn write(mut ctx: PdfContext) -> Vec<u8> {
+    let document = ctx.document;
     page::construct_pages(&mut ctx, &document.pages);
+    pattern::write_patterns(&mut ctx);
     font::write_fonts(&mut ctx);
     image::write_images(&mut ctx);
     external_graphics_state::write_external_graphics_states(&mut ctx);
@@ -46,19 +115,36 @@ const D65_GRAY: Name<'static> = Name(b"d65gray");
 /// Context for exporting a whole PDF document.
 pub struct PdfContext<'a> {
     document: &'a Document,
+    /// The size of the bleed area around each page, if this is for print.
+    bleed: Option<Abs>,
+    /// Whether to write a structure tree.
+    tagged: bool,
+    /// Whether to conform to PDF/A-2b.
+    archival: bool,
+    /// The structure elements of a tagged PDF, in the order they were opened.
+    struct_elems: Vec<StructElem>,
+    /// The currently open structure elements, innermost last. They stay open
+    /// across pages. `None` stands for an artifact.
+    struct_stack: Vec<Option<usize>>,
+    /// The names of labelled locations, which are written as named
+    /// destinations that internal links jump to.
+    dests: HashMap<Location, EcoString>,
     introspector: Introspector,
     writer: PdfWriter,
     pages: Vec<Page>,
     page_heights: Vec<f32>,
     alloc: Ref,
     page_tree_ref: Ref,
+    global_resources_ref: Ref,
     font_refs: Vec<Ref>,
     image_refs: Vec<Ref>,
     ext_gs_refs: Vec<Ref>,
+    pattern_refs: Vec<Ref>,
     page_refs: Vec<Ref>,
     font_map: Remapper<Font>,
     image_map: Remapper<Image>,
     ext_gs_map: Remapper<ExternalGraphicsState>,
+    pattern_map: Remapper<PdfPattern>,
     /// For each font a mapping from used glyphs to their text representation.
     /// May contain multiple chars in case of ligatures or similar things. The
     /// same glyph can have a different text representation within one document,
@@ -70,24 +156,35 @@ pub struct PdfContext<'a> {
 }
 
 impl<'a> PdfContext<'a> {
-    fn new(document: &'a Document) -> Self {
+    fn new(document: &'a Document, bleed: Option<Abs>) -> Self {
         let mut alloc = Ref::new(1);
         let page_tree_ref = alloc.bump();
+        let global_resources_ref = alloc.bump();
+        let introspector = Introspector::new(&document.pages);
         Self {
             document,
-            introspector: Introspector::new(&document.pages),
+            bleed,
+            tagged: false,
+            archival: false,
+            struct_elems: vec![],
+            struct_stack: vec![],
+            dests: named_destinations(&introspector),
+            introspector,
             writer: PdfWriter::new(),
             pages: vec![],
             page_heights: vec![],
             alloc,
             page_tree_ref,
+            global_resources_ref,
             page_refs: vec![],
             font_refs: vec![],
             image_refs: vec![],
             ext_gs_refs: vec![],
+            pattern_refs: vec![],
             font_map: Remapper::new(),
             image_map: Remapper::new(),
             ext_gs_map: Remapper::new(),
+            pattern_map: Remapper::new(),
             glyph_sets: HashMap::new(),
             languages: HashMap::new(),
         }
@@ -112,6 +209,12 @@ fn write_catalog(ctx: &mut PdfContext) {
     // Write the outline tree.
     let outline_root_id = outline::write_outline(ctx);
 
+    // Write the structure tree.
+    let struct_tree_root_id = tags::write_struct_tree(ctx);
+
+    // Write the named destinations.
+    let dests_ref = (!ctx.dests.is_empty()).then(|| write_named_destinations(ctx));
+
     // Write the document information.
     let mut info = ctx.writer.document_info(ctx.alloc.bump());
     let mut xmp = XmpWriter::new();
@@ -134,6 +237,11 @@ fn write_catalog(ctx: &mut PdfContext) {
     xmp.rendition_class(RenditionClass::Proof);
     xmp.pdf_version("1.7");
 
+    if ctx.archival {
+        xmp.pdfa_part("2");
+        xmp.pdfa_conformance("B");
+    }
+
     let xmp_buf = xmp.finish(None);
     let meta_ref = ctx.alloc.bump();
     let mut meta_stream = ctx.writer.stream(meta_ref, xmp_buf.as_bytes());
@@ -141,6 +249,16 @@ fn write_catalog(ctx: &mut PdfContext) {
     meta_stream.pair(Name(b"Subtype"), Name(b"XML"));
     meta_stream.finish();
 
+    // Write the output intent's color profile.
+    let intent_ref = ctx.archival.then(|| {
+        let id = ctx.alloc.bump();
+        let profile = deflate(&icc::srgb());
+        let mut stream = ctx.writer.icc_profile(id, &profile);
+        stream.filter(Filter::FlateDecode);
+        stream.n(3);
+        id
+    });
+
     // Write the document catalog.
     let mut catalog = ctx.writer.catalog(ctx.alloc.bump());
     catalog.pages(ctx.page_tree_ref);
@@ -151,9 +269,100 @@ fn write_catalog(ctx: &mut PdfContext) {
         catalog.outlines(outline_root_id);
     }
 
+    if let Some(dests_ref) = dests_ref {
+        catalog.pair(Name(b"Dests"), dests_ref);
+    }
+
+    if let Some(struct_tree_root_id) = struct_tree_root_id {
+        catalog.pair(Name(b"StructTreeRoot"), struct_tree_root_id);
+        catalog.insert(Name(b"MarkInfo")).dict().pair(Name(b"Marked"), true);
+    }
+
     if let Some(lang) = lang {
         catalog.lang(TextStr(lang.as_str()));
     }
+
+    if let Some(intent_ref) = intent_ref {
+        let mut intents = catalog.insert(Name(b"OutputIntents")).array();
+        let mut intent = intents.push().dict();
+        intent.pair(Name(b"Type"), Name(b"OutputIntent"));
+        intent.pair(Name(b"S"), Name(b"GTS_PDFA1"));
+        intent.pair(Name(b"OutputConditionIdentifier"), TextStr("sRGB"));
+        intent.pair(Name(b"Info"), TextStr("sRGB IEC61966-2.1"));
+        intent.pair(Name(b"DestOutputProfile"), intent_ref);
+    }
+}
+
+/// Assign a destination name to the first element with each label.
+fn named_destinations(introspector: &Introspector) -> HashMap<Location, EcoString> {
+    let mut seen = HashSet::new();
+    let mut dests = HashMap::new();
+    for elem in introspector.all() {
+        let (Some(label), Some(location)) = (elem.label(), elem.location()) else {
+            continue;
+        };
+
+        if seen.insert(label.0.clone()) {
+            dests.insert(location, label.0.clone());
+        }
+    }
+    dests
+}
+
+/// Write the dictionary that maps destination names to positions.
+fn write_named_destinations(ctx: &mut PdfContext) -> Ref {
+    let mut dests: Vec<_> = ctx.dests.iter().collect();
+    dests.sort_by_key(|&(_, name)| name);
+
+    let id = ctx.alloc.bump();
+    let mut dict = ctx.writer.indirect(id).dict();
+    for (&loc, name) in dests {
+        let pos = ctx.introspector.position(loc);
+        let index = pos.page.get() - 1;
+        let Some(&height) = ctx.page_heights.get(index) else { continue };
+        let y = (pos.point.y - Abs::pt(10.0)).max(Abs::zero());
+        dict.insert(Name(name.as_bytes()))
+            .array()
+            .item(ctx.page_refs[index])
+            .item(Name(b"XYZ"))
+            .item(pos.point.x.to_f32())
+            .item(height - y.to_f32())
+            .item(Null);
+    }
+
+    dict.finish();
+    id
+}
+
+/// Whether the frame contains text with glyphs that are missing from their
+/// font.
+fn has_notdef(frame: &Frame) -> bool {
+    frame.items().any(|(_, item)| match item {
+        FrameItem::Group(group) => has_notdef(&group.frame),
+        FrameItem::Text(text) => text.glyphs.iter().any(|glyph| glyph.id == 0),
+        _ => false,
+    })
+}
+
+/// Add a file identifier to the trailer of a finished PDF file.
+///
+/// The writer doesn't give access to the trailer, so the entry is inserted
+/// after the fact. As the trailer follows the cross-reference table, this
+/// doesn't shift any object.
+fn write_file_id(bytes: &mut Vec<u8>, id: u128) {
+    let Some(trailer) = bytes.windows(7).rposition(|window| window == b"trailer")
+    else {
+        return;
+    };
+
+    let Some(dict) = bytes[trailer..].windows(2).position(|window| window == b"<<")
+    else {
+        return;
+    };
+
+    let at = trailer + dict + 2;
+    let entry = format!("\n  /ID [<{id:032X}> <{id:032X}>]");
+    bytes.splice(at..at, entry.bytes());
 }
 
 /// Compress data with the DEFLATE algorithm.
@@ -202,6 +411,10 @@ where
     fn items(&self) -> impl Iterator<Item = &T> + '_ {
         self.to_items.iter()
     }
+
+    fn get(&self, pdf_index: usize) -> Option<&T> {
+        self.to_items.get(pdf_index)
+    }
 }
 
 /// Additional methods for [`Abs`].
@@ -241,3 +454,110 @@ impl RefExt for Ref {
         prev
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::doc::{Destination, Document, Frame, FrameItem, Meta};
+    use crate::geom::{Abs, Color, Point, Size};
+
+    #[test]
+    fn test_pdf_single_page() {
+        let frame = Frame::new(Size::new(Abs::pt(100.0), Abs::pt(50.0)));
+        let document = Document {
+            pages: vec![frame],
+            title: Some("Hello".into()),
+            ..Default::default()
+        };
+
+        let bytes = super::pdf(&document);
+        assert!(bytes.starts_with(b"%PDF-"));
+
+        let pdf = String::from_utf8_lossy(&bytes).into_owned();
+        assert!(pdf.contains("/Count 1"));
+        let pages = pdf.matches("/Type /Page").count();
+        assert_eq!(pages - pdf.matches("/Type /Pages").count(), 1);
+        assert!(pdf.contains("/Title (Hello)"));
+    }
+
+    #[test]
+    fn test_pdf_print_bleed() {
+        let frame = Frame::new(Size::new(Abs::pt(100.0), Abs::pt(50.0)));
+        let pdf = export(frame, |document| super::pdf_print(document, Abs::pt(5.0)));
+        assert!(pdf.contains("/MediaBox [-23 -23 123 73]"));
+        assert!(pdf.contains("/BleedBox [-5 -5 105 55]"));
+        assert!(pdf.contains("/TrimBox [0 0 100 50]"));
+    }
+
+    #[test]
+    fn test_pdf_print_marks_and_fill() {
+        let mut frame = Frame::new(Size::new(Abs::pt(100.0), Abs::pt(50.0)));
+        frame.fill(Color::BLACK.into());
+        let pdf = export(frame, |document| super::pdf_print(document, Abs::pt(5.0)));
+        assert!(pdf.contains("-5 0 m\n-23 0 l\n"));
+        assert!(pdf.contains("100 55 m\n100 73 l\n"));
+        assert!(pdf.contains("-5 -5 110 60 re\n"));
+        assert!(pdf.contains("0 0 100 50 re\n"));
+    }
+
+    #[test]
+    fn test_pdf_a_identification() {
+        let frame = Frame::new(Size::new(Abs::pt(100.0), Abs::pt(50.0)));
+        let pdf = export(frame.clone(), |document| super::pdf_a(document).unwrap());
+        assert!(pdf.contains("/OutputIntents"));
+        assert!(pdf.contains("/S /GTS_PDFA1"));
+        assert!(pdf.contains("/DestOutputProfile"));
+        assert!(pdf.contains("<pdfaid:part>2</pdfaid:part>"));
+        assert!(pdf.contains("<pdfaid:conformance>B</pdfaid:conformance>"));
+
+        let trailer = &pdf[pdf.rfind("trailer").unwrap()..];
+        assert!(trailer.contains("/ID [<"));
+
+        let plain = export(frame, super::pdf);
+        assert!(!plain.contains("pdfaid"));
+        assert!(!plain.contains("/ID"));
+    }
+
+    #[test]
+    fn test_pdf_a_prints_links() {
+        let mut frame = Frame::new(Size::new(Abs::pt(100.0), Abs::pt(50.0)));
+        let link = Meta::Link(Destination::Url("https://typst.app".into()), None);
+        frame.push(Point::zero(), FrameItem::Meta(link, Size::splat(Abs::pt(5.0))));
+        let pdf = export(frame, |document| super::pdf_a(document).unwrap());
+        assert!(pdf.contains("/F 4"));
+    }
+
+    #[test]
+    fn test_pdf_a_rejects_huge_pages() {
+        let frame = Frame::new(Size::new(Abs::pt(100.0), Abs::pt(20000.0)));
+        let document = Document { pages: vec![frame], ..Default::default() };
+        let error = super::pdf_a(&document).unwrap_err();
+        assert_eq!(error, "page 1 is too small or too large for PDF/A");
+    }
+
+    /// Export a document with just the given page and return the file as
+    /// text, with its compressed streams decompressed.
+    pub fn export(frame: Frame, f: impl FnOnce(&Document) -> Vec<u8>) -> String {
+        let find = |haystack: &[u8], needle: &[u8]| {
+            haystack.windows(needle.len()).position(|window| window == needle)
+        };
+
+        let document = Document { pages: vec![frame], ..Default::default() };
+        let bytes = f(&document);
+
+        let mut out = vec![];
+        let mut rest = bytes.as_slice();
+        while let Some(start) = find(rest, b"stream\n") {
+            let (head, tail) = rest.split_at(start + b"stream\n".len());
+            let end = find(tail, b"\nendstream").unwrap();
+            out.extend(head);
+            match miniz_oxide::inflate::decompress_to_vec_zlib(&tail[..end]) {
+                Ok(data) => out.extend(data),
+                Err(_) => out.extend(&tail[..end]),
+            }
+            rest = &tail[end..];
+        }
+
+        out.extend(rest);
+        String::from_utf8_lossy(&out).into_owned()
+    }
+}
@@ -1,17 +1,20 @@
-use ecow::eco_format;
+use ecow::{eco_format, EcoString};
 use pdf_writer::types::{
-    ActionType, AnnotationType, ColorSpaceOperand, LineCapStyle, LineJoinStyle,
+    ActionType, AnnotationFlags, AnnotationType, ColorSpaceOperand, LineCapStyle,
+    LineJoinStyle, TextRenderingMode,
 };
-use pdf_writer::writers::ColorSpace;
-use pdf_writer::{Content, Filter, Finish, Name, Rect, Ref, Str};
+use pdf_writer::writers::{ColorSpace, Resources};
+use pdf_writer::{Content, Filter, Finish, Name, Rect, Ref, Str, TextStr};
 
 use super::external_graphics_state::ExternalGraphicsState;
+use super::pattern::PdfPattern;
+use super::tags;
 use super::{deflate, AbsExt, EmExt, PdfContext, RefExt, D65_GRAY, SRGB};
-use crate::doc::{Destination, Frame, FrameItem, GroupItem, Meta, TextItem};
+use crate::doc::{Destination, Frame, FrameItem, GroupItem, Meta, Role, Tag, TextItem};
 use crate::font::Font;
 use crate::geom::{
-    self, Abs, Color, Em, Geometry, LineCap, LineJoin, Numeric, Paint, Point, Ratio,
-    Shape, Size, Stroke, Transform,
+    self, Abs, Color, Em, Geometry, LineCap, LineJoin, Numeric, Paint, Pattern, Point,
+    Ratio, Shape, Size, Stroke, Transform,
 };
 use crate::image::Image;
 
@@ -30,19 +33,27 @@ pub fn construct_page(ctx: &mut PdfContext, frame: &Frame) {
     ctx.page_refs.push(page_ref);
     ctx.page_heights.push(frame.height().to_f32());
 
+    let tagged = ctx.tagged;
     let mut ctx = PageContext {
         parent: ctx,
-        page_ref,
         uses_opacities: false,
         content: Content::new(),
         state: State::default(),
         saves: vec![],
         bottom: 0.0,
         links: vec![],
+        tagged,
+        mcid: 0,
+        loose: None,
     };
 
     let size = frame.size();
 
+    // Print marks are drawn in the untransformed coordinate system.
+    if let Some(bleed) = ctx.parent.bleed {
+        write_crop_marks(&mut ctx.content, size, bleed);
+    }
+
     // Make the coordinate system start at the top-left.
     ctx.bottom = size.y.to_f32();
     ctx.transform(Transform {
@@ -54,13 +65,26 @@ pub fn construct_page(ctx: &mut PdfContext, frame: &Frame) {
         ty: size.y,
     });
 
+    // Extend the page's fill into the bleed area, so that trimming does not
+    // leave a white edge.
+    if let Some(bleed) = ctx.parent.bleed {
+        if let Some(fill) = page_fill(frame) {
+            let b = bleed.to_f32();
+            let shape = Shape {
+                geometry: Geometry::Rect(size + Size::splat(2.0 * bleed)),
+                ..fill.clone()
+            };
+            write_shape(&mut ctx, -b, -b, &shape);
+        }
+    }
+
     // Encode the page into the content stream.
     write_frame(&mut ctx, frame);
 
     let page = Page {
         size,
         content: ctx.content,
-        id: ctx.page_ref,
+        id: page_ref,
         uses_opacities: ctx.uses_opacities,
         links: ctx.links,
     };
@@ -68,6 +92,28 @@ pub fn construct_page(ctx: &mut PdfContext, frame: &Frame) {
     ctx.parent.pages.push(page);
 }
 
+/// Encode the tile of a pattern into a content stream.
+///
+/// The tile is written in the pattern's own coordinate system, which is
+/// already flipped by the pattern matrix.
+pub fn construct_tile(ctx: &mut PdfContext, frame: &Frame) -> Content {
+    let mut ctx = PageContext {
+        parent: ctx,
+        uses_opacities: false,
+        content: Content::new(),
+        state: State::default(),
+        saves: vec![],
+        bottom: 0.0,
+        links: vec![],
+        tagged: false,
+        mcid: 0,
+        loose: None,
+    };
+
+    write_frame(&mut ctx, frame);
+    ctx.content
+}
+
 /// Write the page tree.
 #[tracing::instrument(skip_all)]
 pub fn write_page_tree(ctx: &mut PdfContext) {
@@ -80,7 +126,13 @@ pub fn write_page_tree(ctx: &mut PdfContext) {
         .count(ctx.page_refs.len() as i32)
         .kids(ctx.page_refs.iter().copied());
 
-    let mut resources = pages.resources();
+    // The resources are shared with the patterns, so they are written into a
+    // separate object.
+    pages.pair(Name(b"Resources"), ctx.global_resources_ref);
+    pages.finish();
+
+    let mut resources =
+        ctx.writer.indirect(ctx.global_resources_ref).start::<Resources>();
     let mut spaces = resources.color_spaces();
     spaces.insert(SRGB).start::<ColorSpace>().srgb();
     spaces.insert(D65_GRAY).start::<ColorSpace>().d65_gray();
@@ -109,8 +161,14 @@ pub fn write_page_tree(ctx: &mut PdfContext) {
     }
     ext_gs_states.finish();
 
+    let mut patterns = resources.patterns();
+    for (pattern_ref, p) in ctx.pattern_map.pdf_indices(&ctx.pattern_refs) {
+        let name = eco_format!("P{}", p);
+        patterns.pair(Name(name.as_bytes()), pattern_ref);
+    }
+    patterns.finish();
+
     resources.finish();
-    pages.finish();
 }
 
 /// Write a page tree node.
@@ -123,9 +181,24 @@ fn write_page(ctx: &mut PdfContext, page: Page) {
 
     let w = page.size.x.to_f32();
     let h = page.size.y.to_f32();
-    page_writer.media_box(Rect::new(0.0, 0.0, w, h));
+    if let Some(bleed) = ctx.bleed {
+        // Leave room for the bleed and the crop marks outside of it.
+        let b = bleed.to_f32();
+        let m = (bleed + CROP_MARK_LENGTH).to_f32();
+        page_writer.media_box(Rect::new(-m, -m, w + m, h + m));
+        page_writer.bleed_box(Rect::new(-b, -b, w + b, h + b));
+        page_writer.trim_box(Rect::new(0.0, 0.0, w, h));
+    } else {
+        page_writer.media_box(Rect::new(0.0, 0.0, w, h));
+    }
     page_writer.contents(content_id);
 
+    // Link the page's marked content to the structure tree.
+    if ctx.tagged {
+        let index = ctx.page_refs.iter().position(|&id| id == page.id).unwrap();
+        page_writer.pair(Name(b"StructParents"), index as i32);
+    }
+
     if page.uses_opacities {
         page_writer
             .group()
@@ -137,10 +210,17 @@ fn write_page(ctx: &mut PdfContext, page: Page) {
     }
 
     let mut annotations = page_writer.annotations();
-    for (dest, rect) in page.links {
+    for Link { dest, alt, rect } in page.links {
         let mut annotation = annotations.push();
         annotation.subtype(AnnotationType::Link).rect(rect);
         annotation.border(0.0, 0.0, 0.0, None);
+        if ctx.archival {
+            // PDF/A requires annotations to be printed.
+            annotation.flags(AnnotationFlags::PRINT);
+        }
+        if let Some(alt) = &alt {
+            annotation.contents(TextStr(alt));
+        }
 
         let pos = match dest {
             Destination::Url(uri) => {
@@ -151,7 +231,16 @@ fn write_page(ctx: &mut PdfContext, page: Page) {
                 continue;
             }
             Destination::Position(pos) => pos,
-            Destination::Location(loc) => ctx.introspector.position(loc),
+            Destination::Location(loc) => {
+                if let Some(name) = ctx.dests.get(&loc) {
+                    annotation
+                        .action()
+                        .action_type(ActionType::GoTo)
+                        .pair(Name(b"D"), Name(name.as_bytes()));
+                    continue;
+                }
+                ctx.introspector.position(loc)
+            }
         };
 
         let index = pos.page.get() - 1;
@@ -174,6 +263,43 @@ fn write_page(ctx: &mut PdfContext, page: Page) {
     ctx.writer.stream(content_id, &data).filter(Filter::FlateDecode);
 }
 
+/// The length of the crop marks around a page exported for print.
+const CROP_MARK_LENGTH: Abs = Abs::raw(18.0);
+
+/// Draw crop marks at the corners of a page, outside of its bleed area.
+fn write_crop_marks(content: &mut Content, size: Size, bleed: Abs) {
+    let start = bleed.to_f32();
+    let end = (bleed + CROP_MARK_LENGTH).to_f32();
+
+    content.save_state();
+    content.set_line_width(0.25);
+    content.set_stroke_gray(0.0);
+    for (x, dx) in [(0.0, -1.0), (size.x.to_f32(), 1.0)] {
+        for (y, dy) in [(0.0, -1.0), (size.y.to_f32(), 1.0)] {
+            content.move_to(x + dx * start, y);
+            content.line_to(x + dx * end, y);
+            content.move_to(x, y + dy * start);
+            content.line_to(x, y + dy * end);
+        }
+    }
+    content.stroke();
+    content.restore_state();
+}
+
+/// The shape that fills a whole page, as added for the page's `fill`.
+fn page_fill(frame: &Frame) -> Option<&Shape> {
+    match frame.items().next()? {
+        (pos, FrameItem::Shape(shape, _))
+            if *pos == Point::zero()
+                && shape.geometry == Geometry::Rect(frame.size())
+                && shape.stroke.is_none() =>
+        {
+            Some(shape)
+        }
+        _ => None,
+    }
+}
+
 /// Data for an exported page.
 pub struct Page {
     /// The indirect object id of the page.
@@ -185,19 +311,34 @@ pub struct Page {
     /// Whether the page uses opacities.
     pub uses_opacities: bool,
     /// Links in the PDF coordinate system.
-    pub links: Vec<(Destination, Rect)>,
+    pub links: Vec<Link>,
+}
+
+/// A link annotation on a page.
+pub struct Link {
+    /// Where the link goes.
+    pub dest: Destination,
+    /// A text describing the link.
+    pub alt: Option<EcoString>,
+    /// The link's area in the PDF coordinate system.
+    pub rect: Rect,
 }
 
 /// An exporter for the contents of a single PDF page.
 struct PageContext<'a, 'b> {
     parent: &'a mut PdfContext<'b>,
-    page_ref: Ref,
     content: Content,
     state: State,
     saves: Vec<State>,
     bottom: f32,
     uses_opacities: bool,
-    links: Vec<(Destination, Rect)>,
+    links: Vec<Link>,
+    /// Whether to tag the content for the structure tree.
+    tagged: bool,
+    /// The next marked content id on this page.
+    mcid: i32,
+    /// The paragraph that collects content outside of any structure element.
+    loose: Option<usize>,
 }
 
 /// A simulated graphics state used to deduplicate graphics state changes and
@@ -214,6 +355,79 @@ struct State {
 }
 
 impl PageContext<'_, '_> {
+    /// Open or close a structure element for the following content.
+    fn tag(&mut self, tag: Tag) {
+        if !self.tagged {
+            return;
+        }
+
+        match tag {
+            Tag::Start(role) => {
+                self.loose = None;
+                let parent = self.parent.struct_stack.last().copied();
+                let heading = parent.flatten().map_or(false, |elem| {
+                    matches!(self.parent.struct_elems[elem].role, Role::Heading(_))
+                });
+
+                let open = match parent {
+                    // Nothing within an artifact is part of the structure.
+                    Some(None) => None,
+                    _ if role == Role::Artifact => None,
+                    // The text of a heading is not wrapped in a paragraph.
+                    _ if role == Role::Paragraph && heading => parent.flatten(),
+                    _ => Some(tags::open(self.parent, role, parent.flatten())),
+                };
+                self.parent.struct_stack.push(open);
+            }
+            Tag::End => {
+                self.parent.struct_stack.pop();
+            }
+        }
+    }
+
+    /// Start a marked content sequence for the following content, so that it
+    /// is linked to the innermost open structure element, or marked as an
+    /// artifact if there is none or `artifact` is set. Returns whether a
+    /// sequence was started.
+    fn begin_marked(&mut self, artifact: bool) -> bool {
+        if !self.tagged {
+            return false;
+        }
+
+        let elem = match self.parent.struct_stack.last() {
+            _ if artifact => None,
+            Some(&elem) => elem,
+            // Collect content outside of any element in a paragraph.
+            None => Some(*self.loose.get_or_insert_with(|| {
+                tags::open(self.parent, Role::Paragraph, None)
+            })),
+        };
+
+        let Some(elem) = elem else {
+            self.content.begin_marked_content(tags::tag(Role::Artifact));
+            return true;
+        };
+
+        let page = self.parent.page_refs.len() - 1;
+        let mcid = self.mcid;
+        self.mcid += 1;
+        tags::mark(self.parent, elem, page, mcid);
+
+        let role = self.parent.struct_elems[elem].role;
+        self.content
+            .begin_marked_content_with_properties(tags::tag(role))
+            .pair(Name(b"MCID"), mcid);
+        true
+    }
+
+    /// End a marked content sequence started by
+    /// [`begin_marked`](Self::begin_marked).
+    fn end_marked(&mut self, marked: bool) {
+        if marked {
+            self.content.end_marked_content();
+        }
+    }
+
     fn save_state(&mut self) {
         self.saves.push(self.state.clone());
         self.content.save_state();
@@ -238,26 +452,12 @@ impl PageContext<'_, '_> {
     }
 
     fn set_opacities(&mut self, stroke: Option<&Stroke>, fill: Option<&Paint>) {
-        let stroke_opacity = stroke
-            .map(|stroke| {
-                let Paint::Solid(color) = stroke.paint;
-                if let Color::Rgba(rgba_color) = color {
-                    rgba_color.a
-                } else {
-                    255
-                }
-            })
-            .unwrap_or(255);
-        let fill_opacity = fill
-            .map(|paint| {
-                let Paint::Solid(color) = paint;
-                if let Color::Rgba(rgba_color) = color {
-                    rgba_color.a
-                } else {
-                    255
-                }
-            })
-            .unwrap_or(255);
+        let opacity = |paint: &Paint| match paint {
+            Paint::Solid(Color::Rgba(rgba_color)) => rgba_color.a,
+            _ => 255,
+        };
+        let stroke_opacity = stroke.map(|stroke| opacity(&stroke.paint)).unwrap_or(255);
+        let fill_opacity = fill.map(opacity).unwrap_or(255);
         self.set_external_graphics_state(&ExternalGraphicsState {
             stroke_opacity,
             fill_opacity,
@@ -286,23 +486,30 @@ impl PageContext<'_, '_> {
         }
     }
 
-    fn set_fill(&mut self, fill: &Paint) {
-        if self.state.fill.as_ref() != Some(fill) {
+    /// Set the fill paint. Patterns are anchored at the given position.
+    fn set_fill(&mut self, fill: &Paint, x: f32, y: f32) {
+        // Patterns depend on the position, so they are never deduplicated.
+        if self.state.fill.as_ref() != Some(fill) || matches!(fill, Paint::Pattern(_)) {
             let f = |c| c as f32 / 255.0;
-            let Paint::Solid(color) = fill;
-            match color {
-                Color::Luma(c) => {
+            match fill {
+                Paint::Solid(Color::Luma(c)) => {
                     self.set_fill_color_space(D65_GRAY);
                     self.content.set_fill_gray(f(c.0));
                 }
-                Color::Rgba(c) => {
+                Paint::Solid(Color::Rgba(c)) => {
                     self.set_fill_color_space(SRGB);
                     self.content.set_fill_color([f(c.r), f(c.g), f(c.b)]);
                 }
-                Color::Cmyk(c) => {
+                Paint::Solid(Color::Cmyk(c)) => {
                     self.reset_fill_color_space();
                     self.content.set_fill_cmyk(f(c.c), f(c.m), f(c.y), f(c.k));
                 }
+                Paint::Pattern(pattern) => {
+                    let name = self.pattern_name(pattern, x, y);
+                    self.reset_fill_color_space();
+                    self.content.set_fill_color_space(ColorSpaceOperand::Pattern);
+                    self.content.set_fill_pattern(None, Name(name.as_bytes()));
+                }
             }
             self.state.fill = Some(fill.clone());
         }
@@ -319,8 +526,12 @@ impl PageContext<'_, '_> {
         self.state.fill_space = None;
     }
 
-    fn set_stroke(&mut self, stroke: &Stroke) {
-        if self.state.stroke.as_ref() != Some(stroke) {
+    /// Set the stroke. Patterns are anchored at the given position.
+    fn set_stroke(&mut self, stroke: &Stroke, x: f32, y: f32) {
+        // Patterns depend on the position, so they are never deduplicated.
+        if self.state.stroke.as_ref() != Some(stroke)
+            || matches!(stroke.paint, Paint::Pattern(_))
+        {
             let Stroke {
                 paint,
                 thickness,
@@ -331,20 +542,25 @@ impl PageContext<'_, '_> {
             } = stroke;
 
             let f = |c| c as f32 / 255.0;
-            let Paint::Solid(color) = paint;
-            match color {
-                Color::Luma(c) => {
+            match paint {
+                Paint::Solid(Color::Luma(c)) => {
                     self.set_stroke_color_space(D65_GRAY);
                     self.content.set_stroke_gray(f(c.0));
                 }
-                Color::Rgba(c) => {
+                Paint::Solid(Color::Rgba(c)) => {
                     self.set_stroke_color_space(SRGB);
                     self.content.set_stroke_color([f(c.r), f(c.g), f(c.b)]);
                 }
-                Color::Cmyk(c) => {
+                Paint::Solid(Color::Cmyk(c)) => {
                     self.reset_stroke_color_space();
                     self.content.set_stroke_cmyk(f(c.c), f(c.m), f(c.y), f(c.k));
                 }
+                Paint::Pattern(pattern) => {
+                    let name = self.pattern_name(pattern, x, y);
+                    self.reset_stroke_color_space();
+                    self.content.set_stroke_color_space(ColorSpaceOperand::Pattern);
+                    self.content.set_stroke_pattern(None, Name(name.as_bytes()));
+                }
             }
 
             self.content.set_line_width(thickness.to_f32());
@@ -381,6 +597,18 @@ impl PageContext<'_, '_> {
     fn reset_stroke_color_space(&mut self) {
         self.state.stroke_space = None;
     }
+
+    /// Register a pattern anchored at the given position in the current
+    /// coordinate system and return its resource name.
+    fn pattern_name(&mut self, pattern: &Pattern, x: f32, y: f32) -> EcoString {
+        let anchor = Transform::translate(Abs::pt(x as f64), Abs::pt(y as f64));
+        let pdf_pattern = PdfPattern {
+            pattern: pattern.clone(),
+            transform: self.state.transform.pre_concat(anchor),
+        };
+        self.parent.pattern_map.insert(pdf_pattern.clone());
+        eco_format!("P{}", self.parent.pattern_map.map(pdf_pattern))
+    }
 }
 
 /// Encode a frame into the content stream.
@@ -390,14 +618,27 @@ fn write_frame(ctx: &mut PageContext, frame: &Frame) {
         let y = pos.y.to_f32();
         match item {
             FrameItem::Group(group) => write_group(ctx, pos, group),
-            FrameItem::Text(text) => write_text(ctx, x, y, text),
-            FrameItem::Shape(shape, _) => write_shape(ctx, x, y, shape),
-            FrameItem::Image(image, size, _) => write_image(ctx, x, y, image, *size),
+            FrameItem::Text(text) => {
+                let marked = ctx.begin_marked(false);
+                write_text(ctx, x, y, text);
+                ctx.end_marked(marked);
+            }
+            FrameItem::Shape(shape, _) => {
+                let marked = ctx.begin_marked(true);
+                write_shape(ctx, x, y, shape);
+                ctx.end_marked(marked);
+            }
+            FrameItem::Image(image, size, _) => {
+                let marked = ctx.begin_marked(false);
+                write_image(ctx, x, y, image, *size);
+                ctx.end_marked(marked);
+            }
             FrameItem::Meta(meta, size) => match meta {
-                Meta::Link(dest) => write_link(ctx, pos, dest, *size),
+                Meta::Link(dest, alt) => write_link(ctx, pos, dest, alt, *size),
                 Meta::Elem(_) => {}
                 Meta::Hide => {}
                 Meta::PageNumbering(_) => {}
+                Meta::Tag(tag) => ctx.tag(*tag),
             },
         }
     }
@@ -410,14 +651,8 @@ fn write_group(ctx: &mut PageContext, pos: Point, group: &GroupItem) {
     ctx.save_state();
     ctx.transform(translation.pre_concat(group.transform));
 
-    if group.clips {
-        let size = group.frame.size();
-        let w = size.x.to_f32();
-        let h = size.y.to_f32();
-        ctx.content.move_to(0.0, 0.0);
-        ctx.content.line_to(w, 0.0);
-        ctx.content.line_to(w, h);
-        ctx.content.line_to(0.0, h);
+    if let Some(clip_path) = &group.clip_path {
+        write_path(ctx, 0.0, 0.0, clip_path);
         ctx.content.clip_nonzero();
         ctx.content.end_path();
     }
@@ -436,10 +671,17 @@ fn write_text(ctx: &mut PageContext, x: f32, y: f32, text: &TextItem) {
         glyph_set.entry(g.id).or_insert_with(|| segment.into());
     }
 
-    ctx.set_fill(&text.fill);
+    ctx.set_fill(&text.fill, x, y);
     ctx.set_font(&text.font, text.size);
-    ctx.set_opacities(None, Some(&text.fill));
+    ctx.set_opacities(text.stroke.as_ref(), Some(&text.fill));
+    if let Some(stroke) = &text.stroke {
+        ctx.set_stroke(stroke, x, y);
+    }
+
     ctx.content.begin_text();
+    if text.stroke.is_some() {
+        ctx.content.set_text_rendering_mode(TextRenderingMode::FillStroke);
+    }
 
     // Positiosn the text.
     ctx.content.set_text_matrix([1.0, 0.0, 0.0, -1.0, x, y]);
@@ -479,6 +721,10 @@ fn write_text(ctx: &mut PageContext, x: f32, y: f32, text: &TextItem) {
 
     items.finish();
     positioned.finish();
+
+    if text.stroke.is_some() {
+        ctx.content.set_text_rendering_mode(TextRenderingMode::Fill);
+    }
     ctx.content.end_text();
 }
 
@@ -497,11 +743,11 @@ fn write_shape(ctx: &mut PageContext, x: f32, y: f32, shape: &Shape) {
     }
 
     if let Some(fill) = &shape.fill {
-        ctx.set_fill(fill);
+        ctx.set_fill(fill, x, y);
     }
 
     if let Some(stroke) = stroke {
-        ctx.set_stroke(stroke);
+        ctx.set_stroke(stroke, x, y);
     }
 
     ctx.set_opacities(stroke, shape.fill.as_ref());
@@ -583,7 +829,13 @@ fn write_image(ctx: &mut PageContext, x: f32, y: f32, image: &Image, size: Size)
 }
 
 /// Save a link for later writing in the annotations dictionary.
-fn write_link(ctx: &mut PageContext, pos: Point, dest: &Destination, size: Size) {
+fn write_link(
+    ctx: &mut PageContext,
+    pos: Point,
+    dest: &Destination,
+    alt: &Option<EcoString>,
+    size: Size,
+) {
     let mut min_x = Abs::inf();
     let mut min_y = Abs::inf();
     let mut max_x = -Abs::inf();
@@ -609,7 +861,7 @@ fn write_link(ctx: &mut PageContext, pos: Point, dest: &Destination, size: Size)
     let y2 = min_y.to_f32();
     let rect = Rect::new(x1, y1, x2, y2);
 
-    ctx.links.push((dest.clone(), rect));
+    ctx.links.push(Link { dest: dest.clone(), alt: alt.clone(), rect });
 }
 
 impl From<&LineCap> for LineCapStyle {
@@ -631,3 +883,35 @@ impl From<&LineJoin> for LineJoinStyle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pdf_writer::Content;
+
+    use crate::doc::{Destination, Frame, FrameItem, Meta};
+    use crate::export::pdf::tests::export;
+    use crate::geom::{Abs, Point, Size};
+
+    #[test]
+    fn test_pdf_link_alt() {
+        let mut frame = Frame::new(Size::splat(Abs::pt(10.0)));
+        let dest = Destination::Url("https://typst.app".into());
+        let meta = Meta::Link(dest, Some("Typst's website".into()));
+        frame.push(Point::zero(), FrameItem::Meta(meta, Size::splat(Abs::pt(5.0))));
+
+        let pdf = export(frame, crate::export::pdf);
+        assert!(pdf.contains("/Contents (Typst's website)"));
+    }
+
+    #[test]
+    fn test_pdf_crop_marks() {
+        let mut content = Content::new();
+        let size = Size::new(Abs::pt(100.0), Abs::pt(50.0));
+        super::write_crop_marks(&mut content, size, Abs::pt(5.0));
+
+        let data = String::from_utf8(content.finish()).unwrap();
+        assert_eq!(data.matches(" m\n").count(), 8);
+        assert!(data.contains("-5 0 m\n-23 0 l\n"));
+        assert!(data.contains("100 55 m\n100 73 l\n"));
+    }
+}
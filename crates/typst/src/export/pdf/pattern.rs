@@ -0,0 +1,79 @@
+use pdf_writer::types::{PaintType, TilingType};
+use pdf_writer::{Filter, Finish, Name, Rect};
+
+use super::{deflate, page, AbsExt, PdfContext, RefExt};
+use crate::geom::{Pattern, Transform};
+
+/// A pattern, anchored in the coordinate system it is painted in.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct PdfPattern {
+    /// The pattern whose tile is repeated.
+    pub pattern: Pattern,
+    /// The transform from the pattern's coordinate system into the default
+    /// coordinate system of the content stream it is used in.
+    pub transform: Transform,
+}
+
+/// Embed all used patterns into the PDF.
+#[tracing::instrument(skip_all)]
+pub fn write_patterns(ctx: &mut PdfContext) {
+    // Writing a tile can register further patterns, so we can't hold on to an
+    // iterator here.
+    let mut index = 0;
+    while let Some(PdfPattern { pattern, transform }) = ctx.pattern_map.get(index).cloned()
+    {
+        let content = page::construct_tile(ctx, pattern.tile());
+        let data = deflate(&content.finish());
+
+        let pattern_ref = ctx.alloc.bump();
+        ctx.pattern_refs.push(pattern_ref);
+
+        let size = pattern.size();
+        let Transform { sx, ky, kx, sy, tx, ty } = transform;
+        let mut tiling = ctx.writer.tiling_pattern(pattern_ref, &data);
+        tiling
+            .tiling_type(TilingType::ConstantSpacing)
+            .paint_type(PaintType::Colored)
+            .bbox(Rect::new(0.0, 0.0, size.x.to_f32(), size.y.to_f32()))
+            .x_step(size.x.to_f32())
+            .y_step(size.y.to_f32())
+            .matrix([
+                sx.get() as _,
+                ky.get() as _,
+                kx.get() as _,
+                sy.get() as _,
+                tx.to_f32(),
+                ty.to_f32(),
+            ]);
+
+        tiling.pair(Name(b"Resources"), ctx.global_resources_ref);
+        tiling.filter(Filter::FlateDecode);
+        tiling.finish();
+
+        index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::doc::{Frame, FrameItem};
+    use crate::export::pdf::tests::export;
+    use crate::geom::{Abs, Color, Geometry, Paint, Pattern, Point, Size};
+    use crate::syntax::Span;
+
+    #[test]
+    fn test_pdf_pattern_fill() {
+        let mut tile = Frame::new(Size::splat(Abs::pt(4.0)));
+        let dot = Geometry::Rect(Size::splat(Abs::pt(2.0))).filled(Color::BLACK.into());
+        tile.push(Point::zero(), FrameItem::Shape(dot, Span::detached()));
+
+        let mut frame = Frame::new(Size::splat(Abs::pt(10.0)));
+        let fill = Paint::Pattern(Pattern::new(tile));
+        let rect = Geometry::Rect(Size::splat(Abs::pt(10.0))).filled(fill);
+        frame.push(Point::zero(), FrameItem::Shape(rect, Span::detached()));
+
+        let pdf = export(frame, crate::export::pdf);
+        assert!(pdf.contains("/PatternType 1"));
+        assert!(pdf.contains("/P0 "));
+    }
+}
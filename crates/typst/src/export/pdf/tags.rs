@@ -0,0 +1,126 @@
+use pdf_writer::{Finish, Name, Ref};
+
+use super::{PdfContext, RefExt};
+use crate::doc::Role;
+
+/// A structure element in a tagged PDF.
+pub struct StructElem {
+    /// The element's role.
+    pub role: Role,
+    /// The index of the element's parent, `None` for top-level elements.
+    pub parent: Option<usize>,
+    /// The element's children, in reading order.
+    pub kids: Vec<StructKid>,
+}
+
+/// A child of a structure element.
+pub enum StructKid {
+    /// Another structure element, identified by its index.
+    Elem(usize),
+    /// A marked content sequence, identified by its page index and marked
+    /// content id.
+    Marked(usize, i32),
+}
+
+/// The structure type's name for a role, which is also used as the tag of
+/// the role's marked content.
+pub fn tag(role: Role) -> Name<'static> {
+    const HEADINGS: [&[u8]; 6] = [b"H1", b"H2", b"H3", b"H4", b"H5", b"H6"];
+    match role {
+        Role::Paragraph => Name(b"P"),
+        Role::Heading(level) => Name(HEADINGS[level.get().min(6) - 1]),
+        Role::List => Name(b"L"),
+        Role::ListItem => Name(b"LI"),
+        Role::Table => Name(b"Table"),
+        Role::Figure => Name(b"Figure"),
+        Role::Note => Name(b"Note"),
+        Role::Artifact => Name(b"Artifact"),
+    }
+}
+
+/// Open a new structure element within the given parent and return its
+/// index.
+pub fn open(ctx: &mut PdfContext, role: Role, parent: Option<usize>) -> usize {
+    let index = ctx.struct_elems.len();
+    ctx.struct_elems.push(StructElem { role, parent, kids: vec![] });
+    if let Some(parent) = parent {
+        ctx.struct_elems[parent].kids.push(StructKid::Elem(index));
+    }
+    index
+}
+
+/// Assign a marked content sequence to a structure element.
+pub fn mark(ctx: &mut PdfContext, elem: usize, page: usize, mcid: i32) {
+    ctx.struct_elems[elem].kids.push(StructKid::Marked(page, mcid));
+}
+
+/// Write the structure tree of a tagged PDF.
+#[tracing::instrument(skip_all)]
+pub fn write_struct_tree(ctx: &mut PdfContext) -> Option<Ref> {
+    if !ctx.tagged {
+        return None;
+    }
+
+    let root_ref = ctx.alloc.bump();
+    let elem_refs: Vec<_> = ctx.struct_elems.iter().map(|_| ctx.alloc.bump()).collect();
+
+    // For each page, the structure elements of its marked content sequences,
+    // indexed by marked content id.
+    let mut parents: Vec<Vec<Option<Ref>>> = vec![vec![]; ctx.page_refs.len()];
+    for (elem, &elem_ref) in ctx.struct_elems.iter().zip(&elem_refs) {
+        let mut dict = ctx.writer.indirect(elem_ref).dict();
+        dict.pair(Name(b"Type"), Name(b"StructElem"));
+        dict.pair(Name(b"S"), tag(elem.role));
+        dict.pair(Name(b"P"), elem.parent.map_or(root_ref, |parent| elem_refs[parent]));
+
+        let mut kids = dict.insert(Name(b"K")).array();
+        for kid in &elem.kids {
+            match *kid {
+                StructKid::Elem(index) => {
+                    kids.item(elem_refs[index]);
+                }
+                StructKid::Marked(page, mcid) => {
+                    let mut mcr = kids.push().dict();
+                    mcr.pair(Name(b"Type"), Name(b"MCR"));
+                    mcr.pair(Name(b"Pg"), ctx.page_refs[page]);
+                    mcr.pair(Name(b"MCID"), mcid);
+                    mcr.finish();
+
+                    let slots = &mut parents[page];
+                    let mcid = mcid as usize;
+                    if slots.len() <= mcid {
+                        slots.resize(mcid + 1, None);
+                    }
+                    slots[mcid] = Some(elem_ref);
+                }
+            }
+        }
+
+        kids.finish();
+        dict.finish();
+    }
+
+    let top = ctx
+        .struct_elems
+        .iter()
+        .zip(&elem_refs)
+        .filter(|(elem, _)| elem.parent.is_none())
+        .map(|(_, &elem_ref)| elem_ref);
+
+    let mut root = ctx.writer.indirect(root_ref).dict();
+    root.pair(Name(b"Type"), Name(b"StructTreeRoot"));
+    root.insert(Name(b"K")).array().items(top);
+
+    let mut tree = root.insert(Name(b"ParentTree")).dict();
+    let mut nums = tree.insert(Name(b"Nums")).array();
+    for (i, refs) in parents.iter().enumerate() {
+        nums.item(i as i32);
+        nums.push().array().items(refs.iter().flatten().copied());
+    }
+
+    nums.finish();
+    tree.finish();
+    root.finish();
+
+    Some(root_ref)
+}
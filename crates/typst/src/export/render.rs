@@ -14,8 +14,8 @@ use usvg::{NodeExt, TreeParsing};
 use crate::doc::{Frame, FrameItem, GroupItem, Meta, TextItem};
 use crate::font::Font;
 use crate::geom::{
-    self, Abs, Color, Geometry, LineCap, LineJoin, Paint, PathItem, Shape, Size, Stroke,
-    Transform,
+    self, Abs, Color, Geometry, LineCap, LineJoin, Paint, PathItem, Pattern, Shape, Size,
+    Stroke, Transform,
 };
 use crate::image::{DecodedImage, Image};
 
@@ -104,10 +104,11 @@ fn render_frame(
                 render_image(canvas, ts, mask, image, *size);
             }
             FrameItem::Meta(meta, _) => match meta {
-                Meta::Link(_) => {}
+                Meta::Link(..) => {}
                 Meta::Elem(_) => {}
                 Meta::PageNumbering(_) => {}
                 Meta::Hide => {}
+                Meta::Tag(_) => {}
             },
         }
     }
@@ -124,14 +125,8 @@ fn render_group(
 
     let mut mask = mask;
     let storage;
-    if group.clips {
-        let size = group.frame.size();
-        let w = size.x.to_f32();
-        let h = size.y.to_f32();
-        if let Some(path) = sk::Rect::from_xywh(0.0, 0.0, w, h)
-            .map(sk::PathBuilder::from_rect)
-            .and_then(|path| path.transform(ts))
-        {
+    if let Some(clip_path) = &group.clip_path {
+        if let Some(path) = convert_path(clip_path).and_then(|path| path.transform(ts)) {
             if let Some(mask) = mask {
                 let mut mask = mask.clone();
                 mask.intersect_path(
@@ -181,7 +176,7 @@ fn render_text(
 
         render_svg_glyph(canvas, ts, mask, text, id)
             .or_else(|| render_bitmap_glyph(canvas, ts, mask, text, id))
-            .or_else(|| render_outline_glyph(canvas, ts, mask, text, id));
+            .or_else(|| render_outline_glyph(canvas, ts, mask, text, id, offset));
 
         x += glyph.x_advance.at(text.size).to_f32();
     }
@@ -309,34 +304,34 @@ fn render_bitmap_glyph(
 }
 
 /// Render an outline glyph into the canvas. This is the "normal" case.
+///
+/// The offset of the glyph within the text run is needed to anchor patterns
+/// at the start of the run.
+#[tracing::instrument(skip_all)]
 fn render_outline_glyph(
     canvas: &mut sk::Pixmap,
     ts: sk::Transform,
     mask: Option<&sk::Mask>,
     text: &TextItem,
     id: GlyphId,
+    offset: f32,
 ) -> Option<()> {
     let ppem = text.size.to_f32() * ts.sy;
 
     // Render a glyph directly as a path. This only happens when the fast glyph
-    // rasterization can't be used due to very large text size or weird
-    // scale/skewing transforms.
-    if ppem > 100.0 || ts.kx != 0.0 || ts.ky != 0.0 || ts.sx != ts.sy {
-        let path = {
-            let mut builder = WrappedPathBuilder(sk::PathBuilder::new());
-            text.font.ttf().outline_glyph(id, &mut builder)?;
-            builder.0.finish()?
-        };
-
-        let paint = (&text.fill).into();
-        let rule = sk::FillRule::default();
+    // rasterization can't be used due to very large text size, weird
+    // scale/skewing transforms, a stroke around the glyph or a pattern fill.
+    let Paint::Solid(color) = text.fill else {
+        return render_outline_glyph_as_path(canvas, ts, mask, text, id, offset);
+    };
 
-        // Flip vertically because font design coordinate
-        // system is Y-up.
-        let scale = text.size.to_f32() / text.font.units_per_em() as f32;
-        let ts = ts.pre_scale(scale, -scale);
-        canvas.fill_path(&path, &paint, rule, ts, mask);
-        return Some(());
+    if ppem > 100.0
+        || ts.kx != 0.0
+        || ts.ky != 0.0
+        || ts.sx != ts.sy
+        || text.stroke.is_some()
+    {
+        return render_outline_glyph_as_path(canvas, ts, mask, text, id, offset);
     }
 
     // Rasterize the glyph with `pixglyph`.
@@ -367,7 +362,6 @@ fn render_outline_glyph(
         let mw = bitmap.width;
         let mh = bitmap.height;
 
-        let Paint::Solid(color) = text.fill;
         let c = color.to_rgba();
 
         // Pad the pixmap with 1 pixel in each dimension so that we do
@@ -405,7 +399,6 @@ fn render_outline_glyph(
         let bottom = top + mh;
 
         // Premultiply the text color.
-        let Paint::Solid(color) = text.fill;
         let c = color.to_rgba();
         let color = sk::ColorU8::from_rgba(c.r, c.g, c.b, 255).premultiply().get();
 
@@ -434,6 +427,55 @@ fn render_outline_glyph(
     Some(())
 }
 
+/// Render an outline glyph directly as a path.
+fn render_outline_glyph_as_path(
+    canvas: &mut sk::Pixmap,
+    ts: sk::Transform,
+    mask: Option<&sk::Mask>,
+    text: &TextItem,
+    id: GlyphId,
+    offset: f32,
+) -> Option<()> {
+    let path = {
+        let mut builder = WrappedPathBuilder(sk::PathBuilder::new());
+        text.font.ttf().outline_glyph(id, &mut builder)?;
+        builder.0.finish()?
+    };
+
+    // Flip vertically because font design coordinate
+    // system is Y-up.
+    let scale = text.size.to_f32() / text.font.units_per_em() as f32;
+    let glyph_ts = ts.pre_scale(scale, -scale);
+
+    // Patterns are anchored at the start of the text run, so they need to be
+    // mapped back from the glyph's coordinate system.
+    let local = sk::Transform::from_scale(1.0 / scale, -1.0 / scale)
+        .pre_translate(-offset, 0.0);
+
+    let mut storage = None;
+    let paint = convert_paint(&text.fill, ts, local, &mut storage);
+    let rule = sk::FillRule::default();
+    canvas.fill_path(&path, &paint, rule, glyph_ts, mask);
+
+    if let Some(stroke) = &text.stroke {
+        let width = stroke.thickness.to_f32() / scale;
+        if width > 0.0 {
+            let mut storage = None;
+            let paint = convert_paint(&stroke.paint, ts, local, &mut storage);
+            let stroke = sk::Stroke {
+                width,
+                line_cap: (&stroke.line_cap).into(),
+                line_join: (&stroke.line_join).into(),
+                miter_limit: stroke.miter_limit.0 as f32,
+                ..Default::default()
+            };
+            canvas.stroke_path(&path, &paint, &stroke, glyph_ts, mask);
+        }
+    }
+
+    Some(())
+}
+
 /// Render a geometrical shape into the canvas.
 fn render_shape(
     canvas: &mut sk::Pixmap,
@@ -457,7 +499,8 @@ fn render_shape(
     };
 
     if let Some(fill) = &shape.fill {
-        let mut paint: sk::Paint = fill.into();
+        let mut storage = None;
+        let mut paint = convert_paint(fill, ts, sk::Transform::identity(), &mut storage);
         if matches!(shape.geometry, Geometry::Rect(_)) {
             paint.anti_alias = false;
         }
@@ -490,7 +533,8 @@ fn render_shape(
 
                 sk::StrokeDash::new(dash_array, pattern.phase.to_f32())
             });
-            let paint = paint.into();
+            let mut storage = None;
+            let paint = convert_paint(paint, ts, sk::Transform::identity(), &mut storage);
             let stroke = sk::Stroke {
                 width,
                 line_cap: line_cap.into(),
@@ -622,14 +666,60 @@ impl From<Transform> for sk::Transform {
     }
 }
 
-impl From<&Paint> for sk::Paint<'static> {
-    fn from(paint: &Paint) -> Self {
-        let mut sk_paint = sk::Paint::default();
-        let Paint::Solid(color) = *paint;
-        sk_paint.set_color(color.into());
-        sk_paint.anti_alias = true;
-        sk_paint
+/// Convert a Typst paint into a tiny-skia paint.
+///
+/// The transform `ts` maps into the canvas and determines the resolution at
+/// which a pattern's tile is rendered. The transform `local` maps from the
+/// pattern's coordinate system into the coordinate system of the painted path.
+/// As the resulting paint borrows the rendered tile, the tile is kept alive in
+/// `storage`.
+fn convert_paint<'a>(
+    paint: &Paint,
+    ts: sk::Transform,
+    local: sk::Transform,
+    storage: &'a mut Option<Arc<sk::Pixmap>>,
+) -> sk::Paint<'a> {
+    let mut sk_paint = sk::Paint::default();
+    sk_paint.anti_alias = true;
+
+    match paint {
+        Paint::Solid(color) => sk_paint.set_color((*color).into()),
+        Paint::Pattern(pattern) => {
+            let size = pattern.size();
+            let w = size.x.to_f32();
+            let h = size.y.to_f32();
+            let pxw = (ts.sx.hypot(ts.ky) * w).round().max(1.0) as u32;
+            let pxh = (ts.kx.hypot(ts.sy) * h).round().max(1.0) as u32;
+            let Some(pixmap) = render_tile(pattern, pxw, pxh) else {
+                sk_paint.set_color(sk::Color::TRANSPARENT);
+                return sk_paint;
+            };
+
+            let pixmap: &'a sk::Pixmap = storage.insert(pixmap);
+            sk_paint.shader = sk::Pattern::new(
+                pixmap.as_ref(),
+                sk::SpreadMode::Repeat,
+                sk::FilterQuality::Nearest,
+                1.0,
+                local.pre_scale(w / pxw as f32, h / pxh as f32),
+            );
+        }
     }
+
+    sk_paint
+}
+
+/// Render the tile of a pattern at the given pixel size.
+#[comemo::memoize]
+fn render_tile(pattern: &Pattern, pxw: u32, pxh: u32) -> Option<Arc<sk::Pixmap>> {
+    let size = pattern.size();
+    let mut pixmap = sk::Pixmap::new(pxw, pxh)?;
+    let ts = sk::Transform::from_scale(
+        pxw as f32 / size.x.to_f32(),
+        pxh as f32 / size.y.to_f32(),
+    );
+    render_frame(&mut pixmap, ts, None, pattern.tile());
+    Some(Arc::new(pixmap))
 }
 
 impl From<Color> for sk::Color {
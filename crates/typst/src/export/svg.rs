@@ -10,8 +10,8 @@ use xmlwriter::XmlWriter;
 use crate::doc::{Frame, FrameItem, GroupItem, TextItem};
 use crate::font::Font;
 use crate::geom::{
-    Abs, Axes, Geometry, LineCap, LineJoin, Paint, PathItem, Ratio, Shape, Size, Stroke,
-    Transform,
+    Abs, Axes, Geometry, LineCap, LineJoin, Paint, PathItem, Pattern, Ratio, Shape, Size,
+    Stroke, Transform,
 };
 use crate::image::{Image, ImageFormat, RasterFormat, VectorFormat};
 use crate::util::hash128;
@@ -58,6 +58,9 @@ struct SVGRenderer {
     /// attribute of the group. The clip path is in the format of `M x y L x y C
     /// x1 y1 x2 y2 x y Z`.
     clip_paths: Deduplicator<EcoString>,
+    /// Patterns are used to fill or stroke shapes and text. They are referenced
+    /// by the `fill` or `stroke` attribute in the format of `url(#id)`.
+    patterns: Deduplicator<RenderedPattern>,
 }
 
 /// Represents a glyph to be rendered.
@@ -72,6 +75,16 @@ enum RenderedGlyph {
     Image { url: EcoString, width: f64, height: f64, ts: Transform },
 }
 
+/// Represents a pattern to be rendered.
+#[derive(Clone)]
+struct RenderedPattern {
+    /// The pattern whose tile is repeated.
+    pattern: Pattern,
+    /// The transform from the pattern's coordinate system into the user space
+    /// of the painted element.
+    ts: Transform,
+}
+
 impl SVGRenderer {
     /// Create a new SVG renderer with empty glyph and clip path.
     fn new() -> Self {
@@ -79,6 +92,7 @@ impl SVGRenderer {
             xml: XmlWriter::new(xmlwriter::Options::default()),
             glyphs: Deduplicator::new('g'),
             clip_paths: Deduplicator::new('c'),
+            patterns: Deduplicator::new('p'),
         }
     }
 
@@ -127,21 +141,16 @@ impl SVGRenderer {
         self.xml.end_element();
     }
 
-    /// Render a group. If the group has `clips` set to true, a clip path will
-    /// be created.
+    /// Render a group. If the group has a clip path, a clip path definition
+    /// will be created.
     fn render_group(&mut self, group: &GroupItem) {
         self.xml.start_element("g");
         self.xml.write_attribute("class", "typst-group");
 
-        if group.clips {
-            let hash = hash128(&group);
-            let size = group.frame.size();
-            let x = size.x.to_pt();
-            let y = size.y.to_pt();
+        if let Some(clip_path) = &group.clip_path {
+            let hash = hash128(clip_path);
             let id = self.clip_paths.insert_with(hash, || {
-                let mut builder = SvgPathBuilder(EcoString::new());
-                builder.rect(x as f32, y as f32);
-                builder.0
+                convert_geometry_to_path(&Geometry::Path(clip_path.clone()))
             });
             self.xml.write_attribute_fmt("clip-path", format_args!("url(#{id})"));
         }
@@ -270,7 +279,23 @@ impl SVGRenderer {
         self.xml.write_attribute_fmt("xlink:href", format_args!("#{id}"));
         self.xml
             .write_attribute_fmt("x", format_args!("{}", x_offset * inv_scale));
-        self.write_fill(&text.fill);
+
+        // The glyph is drawn in font units, so patterns need to be mapped back
+        // into the text's coordinate system.
+        let ts = Transform::translate(Abs::pt(-x_offset * inv_scale), Abs::zero())
+            .pre_concat(Transform::scale(Ratio::new(inv_scale), Ratio::new(-inv_scale)));
+
+        self.write_fill(&text.fill, ts);
+        if let Some(stroke) = &text.stroke {
+            // The stroke needs to be scaled accordingly as well.
+            self.write_stroke(
+                &Stroke {
+                    thickness: stroke.thickness * inv_scale,
+                    ..stroke.clone()
+                },
+                ts,
+            );
+        }
         self.xml.end_element();
 
         Some(())
@@ -282,13 +307,13 @@ impl SVGRenderer {
         self.xml.write_attribute("class", "typst-shape");
 
         if let Some(paint) = &shape.fill {
-            self.write_fill(paint);
+            self.write_fill(paint, Transform::identity());
         } else {
             self.xml.write_attribute("fill", "none");
         }
 
         if let Some(stroke) = &shape.stroke {
-            self.write_stroke(stroke);
+            self.write_stroke(stroke, Transform::identity());
         }
 
         let path = convert_geometry_to_path(&shape.geometry);
@@ -297,15 +322,20 @@ impl SVGRenderer {
     }
 
     /// Write a fill attribute.
-    fn write_fill(&mut self, fill: &Paint) {
-        let Paint::Solid(color) = fill;
-        self.xml.write_attribute("fill", &color.to_rgba().to_hex());
+    ///
+    /// The transform maps a pattern's coordinate system into the user space of
+    /// the element and is ignored for solid colors.
+    fn write_fill(&mut self, fill: &Paint, ts: Transform) {
+        let fill = self.convert_paint(fill, ts);
+        self.xml.write_attribute("fill", &fill);
     }
 
     /// Write a stroke attribute.
-    fn write_stroke(&mut self, stroke: &Stroke) {
-        let Paint::Solid(color) = stroke.paint;
-        self.xml.write_attribute("stroke", &color.to_rgba().to_hex());
+    ///
+    /// The transform is used like in [`write_fill`](Self::write_fill).
+    fn write_stroke(&mut self, stroke: &Stroke, ts: Transform) {
+        let paint = self.convert_paint(&stroke.paint, ts);
+        self.xml.write_attribute("stroke", &paint);
         self.xml.write_attribute("stroke-width", &stroke.thickness.to_pt());
         self.xml.write_attribute(
             "stroke-linecap",
@@ -338,6 +368,22 @@ impl SVGRenderer {
         }
     }
 
+    /// Convert a paint into the value of a `fill` or `stroke` attribute,
+    /// registering a pattern definition if necessary.
+    fn convert_paint(&mut self, paint: &Paint, ts: Transform) -> EcoString {
+        match paint {
+            Paint::Solid(color) => color.to_rgba().to_hex(),
+            Paint::Pattern(pattern) => {
+                let hash = hash128(&(pattern, ts));
+                let id = self.patterns.insert_with(hash, || RenderedPattern {
+                    pattern: pattern.clone(),
+                    ts,
+                });
+                eco_format!("url(#{id})")
+            }
+        }
+    }
+
     /// Render an image element.
     fn render_image(&mut self, image: &Image, size: &Axes<Abs>) {
         let url = convert_image_to_base64_url(image);
@@ -351,6 +397,8 @@ impl SVGRenderer {
 
     /// Finalize the SVG file. This must be called after all rendering is done.
     fn finalize(mut self) -> String {
+        // Patterns go first because their tiles may use glyphs and clip paths.
+        self.write_pattern_defs();
         self.write_glyph_defs();
         self.write_clip_path_defs();
         self.xml.end_document()
@@ -391,6 +439,35 @@ impl SVGRenderer {
         self.xml.end_element();
     }
 
+    /// Build the pattern definitions.
+    fn write_pattern_defs(&mut self) {
+        self.xml.start_element("defs");
+        self.xml.write_attribute("id", "pattern");
+
+        // Rendering a tile can register further patterns, so we can't hold on
+        // to an iterator here.
+        let mut index = 0;
+        while let Some((id, rendered)) = self.patterns.get(index) {
+            let RenderedPattern { pattern, ts } = rendered.clone();
+            let size = pattern.size();
+
+            self.xml.start_element("pattern");
+            self.xml.write_attribute("id", &id);
+            self.xml.write_attribute("patternUnits", "userSpaceOnUse");
+            self.xml.write_attribute("width", &size.x.to_pt());
+            self.xml.write_attribute("height", &size.y.to_pt());
+            if !ts.is_identity() {
+                self.xml.write_attribute("patternTransform", &SvgMatrix(ts));
+            }
+
+            self.render_frame(pattern.tile(), Transform::identity());
+            self.xml.end_element();
+            index += 1;
+        }
+
+        self.xml.end_element();
+    }
+
     /// Build the clip path definitions.
     fn write_clip_path_defs(&mut self) {
         self.xml.start_element("defs");
@@ -577,6 +654,11 @@ impl<T> Deduplicator<T> {
         })
     }
 
+    /// The element at the given index alongside its id.
+    fn get(&self, index: usize) -> Option<(Id, &T)> {
+        self.vec.get(index).map(|v| (Id(self.kind, index), v))
+    }
+
     /// Iterate over the the elements alongside their ids.
     fn iter(&self) -> impl Iterator<Item = (Id, &T)> {
         self.vec.iter().enumerate().map(|(i, v)| (Id(self.kind, i), v))
@@ -651,3 +733,49 @@ impl ttf_parser::OutlineBuilder for SvgPathBuilder {
         write!(&mut self.0, "Z ").unwrap();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::Bytes;
+    use crate::geom::{ellipse_path, Color, Point};
+    use crate::syntax::Span;
+
+    #[test]
+    fn test_svg_pattern_fill() {
+        let mut tile = Frame::new(Size::splat(Abs::pt(4.0)));
+        let dot = Geometry::Rect(Size::splat(Abs::pt(2.0))).filled(Color::BLACK.into());
+        tile.push(Point::zero(), FrameItem::Shape(dot, Span::detached()));
+
+        let mut frame = Frame::new(Size::splat(Abs::pt(10.0)));
+        let fill = Paint::Pattern(Pattern::new(tile));
+        let rect = Geometry::Rect(Size::splat(Abs::pt(10.0))).filled(fill);
+        frame.push(Point::zero(), FrameItem::Shape(rect, Span::detached()));
+
+        let svg = svg(&frame);
+        assert!(svg.contains(r#"fill="url(#p0)""#));
+        assert!(svg.contains(
+            r#"<pattern id="p0" patternUnits="userSpaceOnUse" width="4" height="4">"#
+        ));
+    }
+
+    #[test]
+    fn test_svg_clip_image_to_circle() {
+        let data = Bytes::from_static(
+            br#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"/>"#,
+        );
+        let format = ImageFormat::Vector(VectorFormat::Svg);
+        let image = Image::new(data, format, None).unwrap();
+
+        let size = Size::splat(Abs::pt(20.0));
+        let mut frame = Frame::new(size);
+        frame.push(Point::zero(), FrameItem::Image(image, size, Span::detached()));
+        frame.clip(ellipse_path(size));
+
+        let svg = svg(&frame);
+        let circle = convert_geometry_to_path(&Geometry::Path(ellipse_path(size)));
+        assert!(svg.contains(r#"clip-path="url(#c0)""#));
+        assert!(svg.contains(r#"<clipPath id="c0">"#));
+        assert!(svg.contains(&format!(r#"<path d="{circle}"/>"#)));
+    }
+}
@@ -512,6 +512,31 @@ mod tests {
         assert_eq!(typographic_family("Font Ultra Bold"), "Font");
     }
 
+    #[test]
+    fn test_select_variant() {
+        let info = |weight, style| FontInfo {
+            family: "Family".into(),
+            variant: FontVariant::new(style, weight, FontStretch::NORMAL),
+            flags: FontFlags::empty(),
+            coverage: Coverage::from_vec(vec![]),
+        };
+
+        let mut book = FontBook::new();
+        book.push(info(FontWeight::REGULAR, FontStyle::Normal));
+        book.push(info(FontWeight::BOLD, FontStyle::Normal));
+        book.push(info(FontWeight::REGULAR, FontStyle::Italic));
+
+        let select = |weight, style| {
+            book.select("family", FontVariant::new(style, weight, FontStretch::NORMAL))
+        };
+
+        assert_eq!(select(FontWeight::from_number(700), FontStyle::Normal), Some(1));
+        assert_eq!(select(FontWeight::BLACK, FontStyle::Normal), Some(1));
+        assert_eq!(select(FontWeight::LIGHT, FontStyle::Normal), Some(0));
+        assert_eq!(select(FontWeight::REGULAR, FontStyle::Oblique), Some(2));
+        assert_eq!(select(FontWeight::BOLD, FontStyle::Italic), Some(2));
+    }
+
     #[test]
     fn test_coverage() {
         #[track_caller]
@@ -8,7 +8,7 @@ pub use self::variant::{FontStretch, FontStyle, FontVariant, FontWeight};
 
 use std::fmt::{self, Debug, Formatter};
 use std::hash::{Hash, Hasher};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use ttf_parser::GlyphId;
 
@@ -34,6 +34,9 @@ struct Repr {
     info: FontInfo,
     /// The font's metrics.
     metrics: FontMetrics,
+    /// The horizontal glyph advances, indexed by glyph id. The table is only
+    /// allocated on the first lookup and its entries are populated lazily.
+    advances: OnceLock<Box<[OnceLock<Option<Em>>]>>,
     /// The underlying ttf-parser face.
     ttf: ttf_parser::Face<'static>,
     /// The underlying rustybuzz face.
@@ -56,8 +59,9 @@ impl Font {
         let rusty = rustybuzz::Face::from_slice(slice, index)?;
         let metrics = FontMetrics::from_ttf(&ttf);
         let info = FontInfo::from_ttf(&ttf)?;
+        let advances = OnceLock::new();
 
-        Some(Self(Arc::new(Repr { data, index, info, metrics, ttf, rusty })))
+        Some(Self(Arc::new(Repr { data, index, info, metrics, advances, ttf, rusty })))
     }
 
     /// Parse all fonts in the given data.
@@ -97,11 +101,22 @@ impl Font {
     }
 
     /// Look up the horizontal advance width of a glyph.
+    ///
+    /// Advances are cached, so only the first lookup of a glyph hits the font
+    /// tables.
     pub fn advance(&self, glyph: u16) -> Option<Em> {
-        self.0
-            .ttf
-            .glyph_hor_advance(GlyphId(glyph))
-            .map(|units| self.to_em(units))
+        let advances = self.0.advances.get_or_init(|| {
+            let count = self.0.ttf.number_of_glyphs();
+            (0..count).map(|_| OnceLock::new()).collect()
+        });
+
+        let cell = advances.get(usize::from(glyph))?;
+        *cell.get_or_init(|| {
+            self.0
+                .ttf
+                .glyph_hor_advance(GlyphId(glyph))
+                .map(|units| self.to_em(units))
+        })
     }
 
     /// Lookup a name by id.
@@ -246,3 +261,23 @@ pub enum VerticalFontMetric {
     /// The font's ascender, which typically exceeds the depth of all glyphs.
     Descender,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_font_advance_is_cached() {
+        let data = include_bytes!("../../../../assets/fonts/LinLibertine_R.ttf");
+        let font = Font::new(data.as_slice().into(), 0).unwrap();
+        let glyph = font.ttf().glyph_index('a').unwrap().0;
+        assert!(font.0.advances.get().is_none());
+
+        let advance = font.advance(glyph);
+        assert!(advance.is_some());
+        let cell = &font.0.advances.get().unwrap()[usize::from(glyph)];
+        assert_eq!(cell.get(), Some(&advance));
+        assert_eq!(font.advance(glyph), advance);
+        assert_eq!(font.advance(u16::MAX), None);
+    }
+}
@@ -15,6 +15,7 @@ mod fr;
 mod length;
 mod paint;
 mod path;
+mod pattern;
 mod point;
 mod ratio;
 mod rel;
@@ -36,16 +37,17 @@ pub use self::color::{
 };
 pub use self::corners::{Corner, Corners};
 pub use self::dir::Dir;
-pub use self::ellipse::ellipse;
+pub use self::ellipse::{ellipse, ellipse_path};
 pub use self::em::Em;
 pub use self::fr::Fr;
 pub use self::length::Length;
 pub use self::paint::Paint;
 pub use self::path::{Path, PathItem};
+pub use self::pattern::Pattern;
 pub use self::point::Point;
 pub use self::ratio::Ratio;
 pub use self::rel::Rel;
-pub use self::rounded::rounded_rect;
+pub use self::rounded::{rounded_rect, rounded_rect_path};
 pub use self::scalar::Scalar;
 pub use self::shape::{Geometry, Shape};
 pub use self::sides::{Side, Sides};
@@ -5,6 +5,8 @@ use super::*;
 pub enum Paint {
     /// A solid color.
     Solid(Color),
+    /// A repeating pattern.
+    Pattern(Pattern),
 }
 
 impl<T: Into<Color>> From<T> for Paint {
@@ -17,6 +19,7 @@ impl Debug for Paint {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
             Self::Solid(color) => color.fmt(f),
+            Self::Pattern(pattern) => pattern.fmt(f),
         }
     }
 }
@@ -25,6 +28,8 @@ cast! {
     Paint,
     self => match self {
         Self::Solid(color) => Value::Color(color),
+        Self::Pattern(pattern) => Value::dynamic(pattern),
     },
     color: Color => Self::Solid(color),
+    pattern: Pattern => Self::Pattern(pattern),
 }
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use comemo::Prehashed;
+
+use super::*;
+use crate::doc::Frame;
+
+/// A tiling pattern that repeats a finished frame.
+///
+/// The tile is repeated horizontally and vertically across the painted area,
+/// starting at the origin of the painted item. Tiles that don't fit at the
+/// edges of the area are clipped.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct Pattern(Arc<Prehashed<Frame>>);
+
+impl Pattern {
+    /// Create a new pattern from a tile.
+    pub fn new(tile: Frame) -> Self {
+        Self(Arc::new(Prehashed::new(tile)))
+    }
+
+    /// The frame that is repeated.
+    pub fn tile(&self) -> &Frame {
+        &self.0
+    }
+
+    /// The size of a single tile.
+    pub fn size(&self) -> Size {
+        self.0.size()
+    }
+}
+
+impl Debug for Pattern {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Size { x, y } = self.size();
+        write!(f, "pattern(width: {x:?}, height: {y:?})")
+    }
+}
+
+cast! {
+    type Pattern: "pattern",
+}
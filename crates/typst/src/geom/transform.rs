@@ -34,6 +34,11 @@ impl Transform {
         Self { sx, sy, ..Self::identity() }
     }
 
+    /// A skew transform.
+    pub const fn skew(kx: Ratio, ky: Ratio) -> Self {
+        Self { kx, ky, ..Self::identity() }
+    }
+
     /// A rotate transform.
     pub fn rotate(angle: Angle) -> Self {
         let cos = Ratio::new(angle.cos());
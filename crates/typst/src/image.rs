@@ -278,16 +278,84 @@ fn decode_raster(data: &Bytes, format: RasterFormat) -> StrResult<Rc<DecodedImag
     }
 
     let cursor = io::Cursor::new(data);
-    let (dynamic, icc) = match format {
+    let (mut dynamic, icc) = match format {
         RasterFormat::Jpg => decode_with(JpegDecoder::new(cursor)),
         RasterFormat::Png => decode_with(PngDecoder::new(cursor)),
         RasterFormat::Gif => decode_with(GifDecoder::new(cursor)),
     }
     .map_err(format_image_error)?;
 
+    // Display rotated photos upright.
+    if format == RasterFormat::Jpg {
+        dynamic = apply_orientation(dynamic, jpeg_orientation(data).unwrap_or(1));
+    }
+
     Ok(Rc::new(DecodedImage::Raster(dynamic, icc, format)))
 }
 
+/// Read the EXIF orientation tag from a JPEG's APP1 segment.
+fn jpeg_orientation(data: &[u8]) -> Option<u16> {
+    let mut rest = data.strip_prefix(&[0xFF, 0xD8])?;
+    while let [0xFF, marker, hi, lo, ..] = *rest {
+        let len = u16::from_be_bytes([hi, lo]) as usize;
+        let segment = rest.get(4..2 + len)?;
+        match marker {
+            0xE1 => {
+                if let Some(tiff) = segment.strip_prefix(b"Exif\0\0") {
+                    return exif_orientation(tiff);
+                }
+            }
+            // The image data starts, no metadata follows.
+            0xDA => break,
+            _ => {}
+        }
+        rest = rest.get(2 + len..)?;
+    }
+    None
+}
+
+/// Find the orientation tag in the first IFD of TIFF-structured EXIF data.
+fn exif_orientation(tiff: &[u8]) -> Option<u16> {
+    let little = match tiff.get(..2)? {
+        b"II" => true,
+        b"MM" => false,
+        _ => return None,
+    };
+
+    let u16_at = |i: usize| {
+        let bytes = [*tiff.get(i)?, *tiff.get(i + 1)?];
+        Some(if little { u16::from_le_bytes(bytes) } else { u16::from_be_bytes(bytes) })
+    };
+    let u32_at = |i: usize| {
+        let bytes = tiff.get(i..i + 4)?.try_into().ok()?;
+        Some(if little { u32::from_le_bytes(bytes) } else { u32::from_be_bytes(bytes) })
+    };
+
+    let ifd = u32_at(4)? as usize;
+    let count = u16_at(ifd)? as usize;
+    (0..count)
+        .map(|i| ifd + 2 + 12 * i)
+        .find(|&entry| u16_at(entry) == Some(0x0112))
+        .and_then(|entry| u16_at(entry + 8))
+}
+
+/// Transform an image as prescribed by an EXIF orientation.
+fn apply_orientation(
+    dynamic: image::DynamicImage,
+    orientation: u16,
+) -> image::DynamicImage {
+    match orientation {
+        2 => dynamic.fliph(),
+        3 => dynamic.rotate180(),
+        4 => dynamic.flipv(),
+        5 => dynamic.rotate90().fliph(),
+        6 => dynamic.rotate90(),
+        7 => dynamic.rotate270().fliph(),
+        8 => dynamic.rotate270(),
+        _ => dynamic,
+    }
+}
+
 /// Decode an SVG image.
 #[comemo::memoize]
 fn decode_svg(
@@ -479,3 +547,40 @@ fn format_usvg_error(error: usvg::Error) -> EcoString {
         usvg::Error::ParsingFailed(error) => format_xml_like_error("svg", error),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encode a 2x1 JPEG, optionally tagged with an EXIF orientation.
+    fn jpeg(orientation: Option<u16>) -> Bytes {
+        let buf = image::RgbImage::from_raw(2, 1, vec![255, 0, 0, 0, 0, 255]).unwrap();
+        let mut encoded = vec![];
+        image::codecs::jpeg::JpegEncoder::new(&mut encoded).encode_image(&buf).unwrap();
+
+        let Some(orientation) = orientation else { return encoded.into() };
+        let mut tiff = b"MM\0\x2A\0\0\0\x08\0\x01".to_vec();
+        tiff.extend([0x01, 0x12, 0x00, 0x03, 0, 0, 0, 1]);
+        tiff.extend(orientation.to_be_bytes());
+        tiff.extend([0, 0, 0, 0, 0, 0]);
+        let mut app1 = vec![0xFF, 0xE1];
+        app1.extend((2 + 6 + tiff.len() as u16).to_be_bytes());
+        app1.extend(b"Exif\0\0");
+        app1.extend(tiff);
+        let mut tagged = encoded[..2].to_vec();
+        tagged.extend(app1);
+        tagged.extend(&encoded[2..]);
+        tagged.into()
+    }
+
+    #[test]
+    fn test_jpeg_exif_orientation() {
+        let format = ImageFormat::Raster(RasterFormat::Jpg);
+        let size = |data| Image::new(data, format, None).unwrap().size();
+        assert_eq!(jpeg_orientation(&jpeg(None)), None);
+        assert_eq!(jpeg_orientation(&jpeg(Some(6))), Some(6));
+        assert_eq!(size(jpeg(None)), Axes::new(2, 1));
+        assert_eq!(size(jpeg(Some(1))), Axes::new(2, 1));
+        assert_eq!(size(jpeg(Some(6))), Axes::new(1, 2));
+    }
+}
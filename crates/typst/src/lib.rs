@@ -59,7 +59,7 @@ use comemo::{Prehashed, Track, TrackedMut};
 use ecow::EcoString;
 
 use crate::diag::{FileResult, SourceResult};
-use crate::doc::Document;
+use crate::doc::{Document, Frame};
 use crate::eval::{Bytes, Datetime, Library, Route, Tracer};
 use crate::font::{Font, FontBook};
 use crate::syntax::{FileId, PackageSpec, Source, Span};
@@ -92,6 +92,15 @@ pub fn compile(world: &dyn World, tracer: &mut Tracer) -> SourceResult<Document>
     model::typeset(world, tracer, &module.content())
 }
 
+/// Lay out a source file into one frame per page.
+///
+/// Like [`compile`], but only returns the frame tree. Together with not
+/// exporting the frames, this is useful to profile layout in isolation.
+#[tracing::instrument(skip_all)]
+pub fn layout(world: &dyn World, tracer: &mut Tracer) -> SourceResult<Vec<Frame>> {
+    Ok(compile(world, tracer)?.pages)
+}
+
 /// The environment in which typesetting occurs.
 ///
 /// All loading functions (`main`, `source`, `file`, `font`) should perform
@@ -25,7 +25,7 @@ use typst::doc::{Document, Frame, FrameItem, Meta};
 use typst::eval::{eco_format, func, Bytes, Datetime, Library, NoneValue, Tracer, Value};
 use typst::font::{Font, FontBook};
 use typst::geom::{Abs, Color, RgbaColor, Smart};
-use typst::syntax::{FileId, Source, Span, SyntaxNode, VirtualPath};
+use typst::syntax::{FileId, Source, Span, SyntaxKind, SyntaxNode, VirtualPath};
 use typst::{World, WorldExt};
 use typst_library::layout::{Margin, PageElem};
 use typst_library::text::{TextElem, TextSize};
@@ -543,6 +543,10 @@ fn test_part(
         }
     };
 
+    for frame in &frames {
+        ok &= test_frame_spans(output, &source, frame);
+    }
+
     // Don't retain frames if we don't want to compare with reference images.
     if !compare_ref {
         frames.clear();
@@ -885,6 +889,47 @@ fn test_spans_impl(output: &mut String, node: &SyntaxNode, within: Range<u64>) -
     true
 }
 
+/// Ensure that the glyphs of all text runs from this source map back into it.
+fn test_frame_spans(output: &mut String, source: &Source, frame: &Frame) -> bool {
+    for (_, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => {
+                if !test_frame_spans(output, source, &group.frame) {
+                    return false;
+                }
+            }
+            FrameItem::Text(text) => {
+                for glyph in &text.glyphs {
+                    let (span, offset) = glyph.span;
+                    if span.id() != Some(source.id()) {
+                        continue;
+                    }
+
+                    // Only plain markup text maps one-to-one into the source.
+                    let Some(node) = source.find(span) else { continue };
+                    if node.kind() != SyntaxKind::Text {
+                        continue;
+                    }
+
+                    if usize::from(offset) > node.text().len() {
+                        writeln!(output, "    Text: {:?}", text.text).unwrap();
+                        writeln!(
+                            output,
+                            "    Glyph offset {offset} not in {:?} ❌",
+                            node.text(),
+                        )
+                        .unwrap();
+                        return false;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    true
+}
+
 /// Draw all frames into one image with padding in between.
 fn render(frames: &[Frame]) -> sk::Pixmap {
     let pixel_per_pt = 2.0;
@@ -926,7 +971,7 @@ fn render_links(canvas: &mut sk::Pixmap, ts: sk::Transform, frame: &Frame) {
                 let ts = ts.pre_concat(group.transform.into());
                 render_links(canvas, ts, &group.frame);
             }
-            FrameItem::Meta(Meta::Link(_), size) => {
+            FrameItem::Meta(Meta::Link(..), size) => {
                 let w = size.x.to_pt() as f32;
                 let h = size.y.to_pt() as f32;
                 let rect = sk::Rect::from_xywh(0.0, 0.0, w, h).unwrap();
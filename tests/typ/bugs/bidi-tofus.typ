@@ -2,6 +2,10 @@
 // right-to-left directions does not cause a crash.
 
 ---
+// Warning: 2-32 no font covers the characters "/u{590}/u{591}/u{592}/u{593}"
+// Hint: 2-32 the characters belong to the Hebrew script
+// Warning: 3:2-3:40 no font covers the characters "𰀀𰀁𰀂𰀃"
+// Hint: 3:2-3:40 the characters belong to the Han script
 #"\u{590}\u{591}\u{592}\u{593}"
 
 #"\u{30000}\u{30001}\u{30002}\u{30003}"
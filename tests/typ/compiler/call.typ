@@ -25,6 +25,17 @@
 #g([A], [B])
 #g()[A][B]
 
+---
+// Content can be passed as a named argument next to the body.
+#let card(title: none, body) = {
+  test(type(title), "content")
+  test(title, [Title])
+  test(body, [Body])
+  strong(title) + body
+}
+#card(title: [Title])[Body]
+#test(figure([A], caption: [B]).caption, [B])
+
 ---
 // Trailing comma.
 #test(1 + 1, 2,)
@@ -105,3 +116,24 @@
 // Error: 6-7 unclosed delimiter
 // Error: 1:7-2:1 unclosed string
 #func("]
+
+---
+// Test that omitted arguments of native functions receive their defaults.
+#test(calc.log(100), 2.0)
+#test(calc.log(100), calc.log(100, base: 10))
+#test(calc.round(3.1415), 3.0)
+#test(calc.round(3.1415, digits: 2), 3.14)
+
+---
+// Test that named arguments can be passed in any order.
+#{
+  let a = rect(fill: red, width: 10pt)
+  let b = rect(width: 10pt, fill: red)
+  test(a.fill, b.fill)
+  test(a.width, b.width)
+  test(b.fill, red)
+}
+
+---
+// Error: 7-18 unexpected argument: colour
+#rect(colour: red)
@@ -0,0 +1,27 @@
+// Test coercion of arguments.
+// Ref: false
+
+---
+// Integers are accepted where floats are expected.
+#test(calc.log(100, base: 10), 2.0)
+
+---
+// Non-negative integers are accepted where sizes are expected.
+#test(lorem(0), "")
+
+---
+// Error: 8-10 number must be at least zero
+#lorem(-1)
+
+---
+// Error: 8-12 expected integer, found float
+#lorem(1.5)
+
+---
+// Error: 19-24 expected float, found string
+#calc.log(2, base: "ten")
+
+---
+// Bare numbers are no lengths, but we hint at the unit.
+// Error: 1:17-1:19 expected length, found integer: a length needs a unit - did you mean 12pt?
+#set text(size: 12)
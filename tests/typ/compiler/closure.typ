@@ -17,6 +17,17 @@
   test(adder(2, 3), 5)
 }
 
+---
+// Identity closure, also used as a show rule.
+#let id = x => x
+#test(id(1), 1)
+#test(id[A], [A])
+#show strong: it => {
+  test(it.body, [B])
+  id(it.body)
+}
+*B*
+
 ---
 // Pass closure as argument and return closure.
 // Also uses shorthand syntax for a single argument.
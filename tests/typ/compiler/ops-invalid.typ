@@ -37,6 +37,10 @@
 // Error: 3-22 cannot compare 2.2 with NaN
 #(2.2 <= float("nan"))
 
+---
+// Error: 3-10 cannot compare length and integer
+#(1pt < 1)
+
 ---
 // Error: 3-12 cannot divide by zero
 #(1.2 / 0.0)
@@ -54,6 +58,11 @@
 // Error: 3-10 cannot add integer and string
 #(1 + "2", 40% - 1)
 
+---
+// Fractions can't be mixed with other lengths.
+// Error: 3-13 cannot add fraction and length
+#(1fr + 10pt)
+
 ---
 // Error: 15-23 cannot add integer and string
 #{ let x = 1; x += "2" }
@@ -4,6 +4,8 @@
 ---
 // Multiplication binds stronger than addition.
 #test(1+2*-3, -5)
+#test(2pt + 3pt * 2, 8pt)
+#test(10pt - 4pt / 2, 8pt)
 
 // Subtraction binds stronger than comparison.
 #test(3 == 5 - 2, true)
@@ -31,6 +33,7 @@
 // Parentheses override precedence.
 #test((1), 1)
 #test((1+2)*-3, -9)
+#test((2pt + 3pt) * 2, 10pt)
 
 // Error: 8-9 unclosed delimiter
 #test({(1 + 1}, 2)
@@ -179,6 +179,15 @@
 #test(grid[a] == grid[a], true)
 #test(grid[a] == grid[b], false)
 
+---
+// A percentage is a ratio, distinct from the relative part of a length.
+#test(type(50%), "ratio")
+#test(type(50% + 0pt), "relative length")
+#test(float(50%), 0.5)
+#test(50% * 200pt, 100pt)
+#test((50% + 10pt).ratio, 50%)
+#block(width: 200pt, layout(size => test(50% * size.width, 100pt)))
+
 ---
 // Test comparison operators.
 
@@ -192,6 +201,10 @@
 #test(50% < 40% + 0pt, false)
 #test(40% + 0pt < 50% + 0pt, true)
 #test(1em < 2em, true)
+#test(3pt < 5pt, true)
+#test(2 >= 1.5, true)
+#test(1 == 1 and 2 < 1, false)
+#test(not 1 != 1 or false, true)
 
 ---
 // Test assignment operators.
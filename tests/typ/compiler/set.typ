@@ -64,3 +64,20 @@ Hello *#x*
 ---
 // Error: 12-26 set is only allowed directly in code and content blocks
 #{ let x = set text(blue) }
+
+---
+// Ref: false
+// Test that set rules only affect following content in the same scope.
+#let probe = style(styles => [#metadata(measure([X], styles).height) <probe>])
+#probe
+#[
+  #set text(size: 20pt)
+  #probe
+]
+#probe
+
+#locate(loc => {
+  let (before, inside, after) = query(<probe>, loc).map(it => it.value)
+  test(before < inside, true)
+  test(before, after)
+})
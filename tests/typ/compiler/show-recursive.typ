@@ -49,3 +49,20 @@
   - Nested
   - List
 - Recursive!
+
+---
+// Ref: false
+// Test that a show rule wrapping its heading applies to each heading.
+#show heading: it => [#metadata(it.body) <shown>#it]
+= One
+= Two
+
+#locate(loc => {
+  test(query(<shown>, loc).map(it => it.value), ([One], [Two]))
+})
+
+---
+// Test that a show rule producing the element it matches doesn't loop.
+// Error: 22-38 maximum show rule depth exceeded
+#show heading: it => heading(it.body)
+= Hello
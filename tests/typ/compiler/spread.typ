@@ -121,3 +121,13 @@
   // Error: 4-10 missing argument: d
   f(1, 2)
 }
+
+---
+// Test that native variadic functions accept any number of positional
+// arguments.
+#test(calc.max(1, 2, 3, 4, 5, 6, 7, 8), 8)
+#test(calc.min(..range(100)), 0)
+
+---
+// Error: 14-15 unexpected argument
+#calc.abs(1, 2)
@@ -5,6 +5,12 @@
 // Test the `len` method.
 #test("Hello World!".len(), 12)
 
+---
+// The length is in bytes, but code points can be counted separately.
+#test("é".len(), 2)
+#test("é".codepoints().len(), 1)
+#test("🏳️‍🌈".clusters().len(), 1)
+
 ---
 // Test the `first` and `last` methods.
 #test("Hello".first(), "H")
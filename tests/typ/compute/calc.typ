@@ -92,6 +92,16 @@
 #test(calc.min(3.5, 1e2, -0.1, 3), -0.1)
 #test(calc.max(-3, 11), 11)
 #test(calc.min("hi"), "hi")
+#test(calc.max(1, 2, 3), 3)
+#test(calc.max(100pt, 50pt), 100pt)
+
+---
+// Test rounding, flooring and ceiling.
+#test(calc.round(2.6), 3)
+#test(calc.floor(2.6), 2)
+#test(calc.ceil(2.2), 3)
+#test(calc.abs(-2.5), 2.5)
+#test(type(calc.floor(2.6)), "integer")
 
 ---
 // Test the `pow`, `log`, `exp`, and `ln` functions.
@@ -200,6 +210,10 @@
 // Error: 16-19 cannot compare 1pt with 1em
 #calc.max(1em, 1pt)
 
+---
+// Error: 18-19 cannot compare length and integer
+#calc.max(100pt, 1)
+
 ---
 // Test the `range` function.
 #test(range(4), (0, 1, 2, 3))
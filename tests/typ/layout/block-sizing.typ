@@ -22,3 +22,22 @@
 #block(width: 60pt, height: 80pt, layout(size => [
   This block has a width of #size.width and height of #size.height
 ]))
+
+---
+// Relative lengths are resolved against the size of the container.
+// Ref: false
+#block(width: 200pt, block(width: 50% - 10pt, layout(size => {
+  test(size.width, 90pt)
+})))
+
+---
+// An unbreakable block that doesn't fit moves to the next page as a whole.
+// Ref: false
+#set page(height: 100pt, margin: 0pt)
+#v(70pt)
+#block(breakable: false, height: 50pt)[#metadata(none) <kept>]
+#locate(loc => {
+  let pos = query(<kept>, loc).first().location().position()
+  test(pos.page, 2)
+  test(pos.y, 0pt)
+})
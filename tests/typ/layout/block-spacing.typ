@@ -7,3 +7,23 @@ Hello
 There
 
 #block(spacing: 20pt)[Further down]
+
+---
+// Test that spacing around headings collapses with paragraph spacing.
+// Ref: false
+#set page(height: 200pt, margin: 0pt)
+#set text(size: 10pt)
+#set block(spacing: 5pt)
+= Alpha
+Body
+= Beta
+#locate(loc => style(styles => {
+  let (alpha, beta) = query(heading, loc).map(it => it.location().position().y)
+  let heading = measure(text(14pt, weight: "bold")[Alpha], styles).height
+  let body = measure([Body], styles).height
+
+  // The heading's spacing of 0.75em below and 1.8em above (relative to its
+  // 1.4em font size) wins over the paragraph's.
+  let expected = heading + 7.5pt + body + 18pt
+  test(calc.abs((beta - alpha - expected) / 1pt) < 0.01, true)
+}))
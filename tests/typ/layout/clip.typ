@@ -40,3 +40,45 @@ First!
   But, soft! what light through yonder window breaks? It is the east, and Juliet
   is the sun.
 ]
+
+---
+// Test clipping to the outline of shapes. Nested clips intersect.
+// Ref: false
+#circle(radius: 15pt, inset: 0pt, clip: true)[
+  #image("/files/tiger.jpg", width: 30pt)
+]
+#rect(radius: 5pt, inset: 0pt, clip: true)[
+  #box(radius: 50%, clip: true, rect(width: 40pt, height: 20pt, fill: red))
+]
+#style(styles => {
+  let size = measure(circle(radius: 15pt, clip: true, rect(width: 60pt)), styles)
+  test(size.width, 30pt)
+})
+
+---
+// Test that truncated content in a box is marked with an ellipsis.
+// Ref: false
+#show "…": it => [#metadata(none) <ellipsis>#it]
+#box(width: 30pt, height: 12pt, overflow: "ellipsis", lorem(10))
+#box(width: 30pt, height: 12pt, overflow: "ellipsis")[Hi]
+#locate(loc => {
+  let marks = query(<ellipsis>, loc)
+  test(marks.len(), 1)
+  let x = marks.first().location().position().x
+  test(x > 25pt and x < 40pt, true)
+})
+
+---
+// Test that hidden overflow warns about truncated content.
+// Ref: false
+// Warning: 2-63 content overflows the box and is clipped
+// Hint: 2-63 use `overflow: "ellipsis"` to mark the truncation
+#box(width: 30pt, height: 12pt, overflow: "hidden", lorem(10))
+#box(width: 30pt, height: 12pt, overflow: "hidden")[Hi]
+
+---
+// Test that clipped descenders count as overflowing content.
+// Ref: false
+// Warning: 2-55 content overflows the box and is clipped
+// Hint: 2-55 use `overflow: "ellipsis"` to mark the truncation
+#box(width: 30pt, height: 8pt, overflow: "hidden")[gy]
@@ -103,3 +103,53 @@ This is a normal page. Very normal.
 // Test a page with zero columns.
 // Error: 49-50 number must be positive
 #set page(height: auto, width: 7.05cm, columns: 0)
+
+---
+// Test that page columns flow a paragraph into the second column.
+// Ref: false
+#set page(height: 60pt, columns: 2)
+#metadata(none) <start> #lorem(12) #metadata(none) <end>
+
+#locate(loc => {
+  let start = query(<start>, loc).first().location().position()
+  let end = query(<end>, loc).first().location().position()
+  test((start.page, end.page), (1, 1))
+  test(start.x, 10pt)
+  test(end.x >= 10pt + 48pt + 4pt, true)
+  test(end.y < 50pt, true)
+})
+
+---
+// Test that balanced columns end at nearly the same height.
+// Ref: false
+#set page(height: 200pt)
+#columns(2, balance: true)[
+  #metadata(none) <start> #lorem(12) #metadata(none) <end>
+]
+#metadata(none) <after>
+
+#locate(loc => {
+  let pos(label) = query(label, loc).first().location().position()
+  let (start, end, after) = (pos(<start>), pos(<end>), pos(<after>))
+  test((start.page, end.page, after.page), (1, 1, 1))
+  test(end.x >= 10pt + 48pt + 4pt, true)
+  test(after.y - start.y < 50pt, true)
+  test(after.y - end.y < 15pt, true)
+})
+
+---
+// Test that columns are also balanced in a container of automatic height.
+// Ref: false
+#set page(height: auto)
+#block(columns(2, balance: true)[
+  #metadata(none) <start> #lorem(12) #metadata(none) <end>
+])
+#metadata(none) <after>
+
+#locate(loc => {
+  let pos(label) = query(label, loc).first().location().position()
+  let (start, end, after) = (pos(<start>), pos(<end>), pos(<after>))
+  test(end.x >= 10pt + 48pt + 4pt, true)
+  test(after.y - start.y < 50pt, true)
+  test(after.y - end.y < 15pt, true)
+})
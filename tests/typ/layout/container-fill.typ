@@ -5,3 +5,12 @@
   #box(fill: teal, outset: 2pt)[tempor]
   #words.slice(13).join(" ")
 ]
+
+---
+// Outset does not affect the layout size.
+// Ref: false
+#style(styles => {
+  let plain = measure(box(width: 20pt, height: 10pt), styles)
+  let outset = measure(box(width: 20pt, height: 10pt, fill: red, radius: 3pt, outset: (x: 4pt, y: 2pt)), styles)
+  test(plain, outset)
+})
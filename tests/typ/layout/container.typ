@@ -48,3 +48,31 @@ First!
   But, soft! what light through yonder window breaks? It is the east, and Juliet
   is the sun.
 ]
+
+---
+// A block after inline text starts on a new line, while a box stays inline.
+// Ref: false
+#style(styles => {
+  let inline = measure([A #box[B]], styles)
+  let broken = measure([A #block[B]], styles)
+  test(broken.height > inline.height, true)
+})
+
+---
+// Inset grows the container around a fixed-size body on each side.
+// Ref: false
+#style(styles => {
+  let body = rect(width: 20pt, height: 10pt)
+  test(measure(box(inset: 4pt, body), styles), (width: 28pt, height: 18pt))
+  test(measure(rect(inset: (x: 2pt, top: 1pt, bottom: 3pt), body), styles), (width: 24pt, height: 14pt))
+})
+
+---
+// An auto-sized container shrinks to its body, a sized one expands.
+// Ref: false
+#style(styles => {
+  let body = rect(width: 20pt, height: 10pt)
+  test(measure(box(body), styles), (width: 20pt, height: 10pt))
+  test(measure(box(width: 50pt, body), styles), (width: 50pt, height: 10pt))
+  test(measure(block(height: 30pt, body), styles), (width: 20pt, height: 30pt))
+})
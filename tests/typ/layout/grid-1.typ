@@ -39,3 +39,20 @@
   align(center)[A bit more to the top],
   [],
 )
+
+---
+// Test that column gutters are reserved before distributing fractions.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#grid(
+  columns: (1fr, 1fr, 1fr),
+  column-gutter: 10pt,
+  [#metadata(none) <a>], [#metadata(none) <b>], [#metadata(none) <c>],
+)
+#locate(loc => {
+  let x(label) = query(label, loc).first().location().position().x
+  let width = (100pt - 2 * 10pt) / 3
+  test(x(<a>), 0pt)
+  test(calc.abs((x(<b>) - width - 10pt) / 1pt) < 0.01, true)
+  test(calc.abs((x(<c>) - 2 * width - 20pt) / 1pt) < 0.01, true)
+})
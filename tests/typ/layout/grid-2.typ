@@ -27,3 +27,18 @@
   [3,497.12 \$],
   [_145,659.17 \$_],
 )
+
+---
+// Test that an auto row is as tall as its tallest cell.
+// Ref: false
+#set page(margin: 0pt)
+#grid(
+  columns: 2,
+  [A], [A \ B \ C],
+  [#metadata(none) <next>],
+)
+#locate(loc => style(styles => {
+  let y = query(<next>, loc).first().location().position().y
+  let height = measure([A \ B \ C], styles).height
+  test(calc.abs((y - height) / 1pt) < 0.01, true)
+}))
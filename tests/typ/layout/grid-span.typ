@@ -0,0 +1,76 @@
+// Test cells spanning multiple columns and rows.
+// Ref: false
+
+---
+// A cell spanning both columns of a 2x2 grid pushes the other cells into the
+// next row.
+#grid(
+  columns: 2,
+  grid.cell(colspan: 2)[#metadata(none) <a>],
+  [#metadata(none) <b>],
+  [#metadata(none) <c>],
+)
+
+#locate(loc => {
+  let a = query(<a>, loc).first().location().position()
+  let b = query(<b>, loc).first().location().position()
+  let c = query(<c>, loc).first().location().position()
+  test(a.x, b.x)
+  test(b.y, c.y)
+  test(a.y < b.y, true)
+  test(b.x < c.x, true)
+})
+
+---
+// Auto columns grow to fit a spanning cell.
+#style(styles => {
+  let size = measure(
+    grid(columns: (auto, auto), grid.cell(colspan: 2, rect(width: 60pt)), [B], [C]),
+    styles,
+  )
+  test(size.width, 60pt)
+})
+
+---
+// Auto rows grow to fit a cell spanning them.
+#style(styles => {
+  let size = measure(
+    grid(
+      columns: 2,
+      grid.cell(rowspan: 2, rect(height: 40pt)),
+      rect(height: 10pt),
+      rect(height: 10pt),
+    ),
+    styles,
+  )
+  test(size.height, 40pt)
+})
+
+---
+// Lines are not drawn through spanning cells.
+#table(
+  columns: 3,
+  table.cell(colspan: 2)[*Wide*], [A],
+  table.cell(rowspan: 2)[*Tall*], [B], [C],
+  [D], [E],
+)
+
+---
+// Error: 24-48 cell would span beyond the last column
+#grid(columns: 2, [A], grid.cell(colspan: 2)[B])
+
+---
+// Error: 55-79 cell would overlap with another cell
+#grid(columns: 3, [A], grid.cell(rowspan: 2)[B], [C], grid.cell(colspan: 2)[D])
+
+---
+// A cell spanning rows can't be split across pages.
+#set page(height: 60pt)
+#grid(
+  columns: 2,
+  rows: 20pt,
+  [A], [B],
+  // Error: 3-24 cell spanning multiple rows cannot break across regions
+  grid.cell(rowspan: 2)[C], [D],
+  [E],
+)
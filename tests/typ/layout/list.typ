@@ -55,3 +55,25 @@ Not in list
 #set align(horizon)
 
 - ABCDEF\ GHIJKL\ MNOPQR
+
+---
+// Consecutive items form a single list and indentation nests them.
+// Ref: false
+#let lists = state("lists", ())
+#show list: it => {
+  lists.update(l => l + ((it.children.len(), it.tight),))
+  it
+}
+
+- A
+- B
+  - C
+- D
+
+Separate
+
+- E
+
+- F
+
+#locate(loc => test(lists.final(loc), ((3, true), (1, true), (2, false))))
@@ -0,0 +1,17 @@
+// Test the `measure` function.
+
+---
+// Ref: false
+#style(styles => {
+  let fixed = box(width: 40pt, height: 20pt)
+  test(measure(fixed, styles), (width: 40pt, height: 20pt))
+})
+
+---
+// Relative sizes resolve against the given area.
+// Ref: false
+#style(styles => {
+  let relative = box(width: 50%, height: 25%)
+  let size = measure(relative, styles, width: 100pt, height: 80pt)
+  test(size, (width: 50pt, height: 20pt))
+})
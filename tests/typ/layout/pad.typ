@@ -28,3 +28,12 @@ Hi #box(pad(left: 10pt)[A]) there
 ---
 // Test that padding adding up to 100% does not panic.
 #pad(50%)[]
+
+---
+// Test that negative padding shrinks, but never below zero.
+// Ref: false
+#style(styles => {
+  let body = box(width: 30pt, height: 10pt)
+  test(measure(pad(x: -5pt, body), styles), (width: 20pt, height: 10pt))
+  test(measure(pad(x: -20pt, y: -10pt, body), styles), (width: 0pt, height: 0pt))
+})
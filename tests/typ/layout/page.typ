@@ -40,3 +40,20 @@
   h(1em)
   place(left, rect(width: 80pt, stroke: blue))
 })
+
+---
+// Test that the background is laid out into the full area of every page.
+// Ref: false
+#set page(
+  height: 40pt,
+  fill: aqua,
+  background: layout(size => [#metadata(size) <bg>]),
+)
+
+A #pagebreak() B
+
+#locate(loc => {
+  let backgrounds = query(<bg>, loc)
+  test(backgrounds.map(bg => bg.location().page()), (1, 2))
+  test(backgrounds.map(bg => bg.value), ((width: 120pt, height: 40pt),) * 2)
+})
@@ -43,3 +43,18 @@ First
 #page[Second]
 #pagebreak(weak: true)
 #page[Third]
+
+---
+// Test that page runs are numbered consecutively.
+// Ref: false
+#set page(width: 40pt, height: 30pt)
+#metadata(none) <a>
+#pagebreak()
+#pagebreak()
+#metadata(none) <b>
+#set page(width: 50pt)
+#metadata(none) <c>
+#locate(loc => {
+  let pages = (<a>, <b>, <c>).map(l => query(l, loc).first().location().page())
+  test(pages, (1, 3, 4))
+})
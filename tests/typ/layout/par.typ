@@ -34,3 +34,55 @@ fn main() {}
 - List
 
 Paragraph
+
+---
+// Test limiting the number of lines of a paragraph.
+// Ref: false
+#style(styles => {
+  let size(body) = measure(body, styles)
+  let two = size[A \ B]
+  let cut = size(par(max-lines: 2)[A \ B \ C])
+  let plain = size(par(max-lines: 2, ellipsis: false)[A \ B \ C])
+  test(cut.height, two.height)
+  test(cut.width > two.width, true)
+  test(plain, two)
+  test(size(par(min-lines: 3)[A]).height, size[A \ B \ C].height)
+  test(size(par(min-lines: 1)[A \ B]), two)
+  test(size(par(min-lines: 3)[A \ B]).height, size[A \ B \ C].height)
+})
+
+---
+// Test that line spacing and paragraph spacing are independent.
+// Ref: false
+#set par(leading: 15pt)
+#show par: set block(spacing: 20pt)
+#style(styles => {
+  let height(body) = measure(body, styles).height
+  let line = height[A]
+  test(calc.abs((height[A \ B] - 2 * line - 15pt) / 1pt) < 0.01, true)
+  test(calc.abs((height[A #parbreak() B] - 2 * line - 20pt) / 1pt) < 0.01, true)
+})
+
+---
+// Test that tabs advance to the next tab stop.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  let tabbed(body) = width(par(tab-size: 40pt, body))
+  let b = width[B]
+  test(calc.abs((tabbed[#"A\tB"] - 40pt - b) / 1pt) < 0.01, true)
+  test(calc.abs((tabbed[#"AAA\tB"] - 40pt - b) / 1pt) < 0.01, true)
+  test(calc.abs((tabbed[#"A\t\tB"] - 80pt - b) / 1pt) < 0.01, true)
+})
+
+---
+// Test that tabs in justified text are fixed advances.
+// Ref: false
+#set page(width: 160pt, margin: 0pt)
+#set par(justify: true, tab-size: 40pt)
+#"A\t"#metadata(none) <after> and then some more words that wrap onto the next line.
+
+#locate(loc => {
+  let x = query(<after>, loc).first().location().position().x
+  test(calc.abs((x - 40pt) / 1pt) < 0.01, true)
+})
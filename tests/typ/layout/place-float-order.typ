@@ -0,0 +1,34 @@
+// Test that floats end up at the top or bottom of their page.
+
+---
+// Ref: false
+#set page(height: 250pt, width: 150pt)
+#metadata(none) <body>
+#lorem(10)
+#figure(placement: top, rect(height: 30pt))[Top] <top>
+#figure(placement: bottom, rect(height: 30pt))[Bottom] <bottom>
+
+#locate(loc => {
+  let body = query(<body>, loc).first().location().position()
+  let top = query(<top>, loc).first().location().position()
+  let bottom = query(<bottom>, loc).first().location().position()
+  test(top.page, body.page)
+  test(bottom.page, body.page)
+  test(top.y < body.y, true)
+  test(bottom.y > body.y, true)
+})
+
+---
+// Ref: false
+// A float that fits an empty page but not the space that remains after the
+// preceding content moves to the next page.
+#set page(height: 120pt, width: 150pt, margin: 0pt)
+#figure(placement: top, rect(height: 60pt))[Fits] <fits>
+#pagebreak()
+#v(50pt)
+#figure(placement: top, rect(height: 60pt))[Moved] <moved>
+
+#locate(loc => {
+  test(query(<fits>, loc).first().location().page(), 1)
+  test(query(<moved>, loc).first().location().page(), 3)
+})
@@ -33,3 +33,38 @@ First
 #place(bottom + right)[Placed]
 
 Second
+
+---
+// Test that placed content can extend past the margins to the page edges.
+// Ref: false
+#set page(height: 60pt, margin: 10pt)
+#place(top + left, dx: -10pt, dy: -10pt, block(
+  width: 100% + 20pt,
+  height: 100% + 20pt,
+  layout(size => [#metadata(size) <bleed>]),
+))
+
+#locate(loc => {
+  let bleed = query(<bleed>, loc).first()
+  test(bleed.value, (width: 120pt, height: 60pt))
+  test(bleed.location().position(), (page: 1, x: 0pt, y: 0pt))
+})
+
+---
+// Test that the lint reports placed content overlapping body text.
+// Ref: false
+#set place(lint: true)
+// Warning: 1-5 this content is overlapped
+Body text
+
+// Warning: 2-63 placed content overlaps flowed content
+// Hint: 2-63 set `lint: false` if the overlap is intended
+#place(top + left, rect(width: 20pt, height: 20pt, fill: red))
+
+---
+// Test that intentional overlaps can opt out of the lint.
+// Ref: false
+#set place(lint: true)
+Body text
+
+#place(top + left, lint: false, rect(width: 20pt, height: 20pt, fill: red))
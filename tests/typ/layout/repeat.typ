@@ -42,3 +42,26 @@ A#box(width: 1fr, repeat(rect(width: 6em, height: 0.7em)))B
 // Error: 2:2-2:13 repeat with no size restrictions
 #set page(width: auto)
 #repeat(".")
+
+---
+// Test that repeating zero-width content terminates.
+// Ref: false
+A#box(width: 1fr, repeat(box(width: 0pt, height: 5pt)))B
+
+---
+// Test that unjustified leaders line up at the end.
+// Ref: false
+#set align(end)
+#set repeat(gap: 2pt, justify: false)
+#let dot = box(width: 3pt, height: 1pt)
+
+A #box(width: 1fr, repeat[#metadata(none) <a>#dot]) 1 \
+Much longer #box(width: 1fr, repeat[#metadata(none) <b>#dot]) 1
+
+#locate(loc => {
+  let a = query(<a>, loc).first().location().position()
+  let b = query(<b>, loc).first().location().position()
+  let steps = (b.x - a.x) / 5pt
+  test(steps > 0, true)
+  test(calc.abs(steps - calc.round(steps)) < 0.01, true)
+})
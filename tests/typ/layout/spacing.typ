@@ -34,3 +34,58 @@ A #h(1fr) B
 // Missing spacing.
 // Error: 11-13 missing argument: amount
 Totally #h() ignored
+
+---
+// Weak spacing collapses at the start of a flow and adjacent weak spacings
+// collapse to the larger one.
+// Ref: false
+#style(styles => {
+  let height(body) = measure(block(body), styles).height
+  test(height[#v(10pt, weak: true) A], height[A])
+  test(height[A #v(10pt, weak: true)], height[A])
+  test(
+    height[A #v(5pt, weak: true) #v(10pt, weak: true) B],
+    height[A #v(10pt, weak: true) B],
+  )
+  test(height[A #v(10pt) #v(10pt) B] > height[A #v(10pt, weak: true) B], true)
+})
+
+---
+// Negative spacing pulls the following content back.
+// Ref: false
+#style(styles => {
+  let plain = measure([A#h(0pt)B], styles).width
+  let pulled = measure([A#h(-3pt)B], styles).width
+  test(calc.abs((plain - pulled - 3pt) / 1pt) < 0.01, true)
+})
+
+---
+// Test relative spacing against the remaining space.
+// Ref: false
+#set page(height: 100pt, margin: 0pt)
+#v(20pt)
+#v(50%, remaining: true)
+#metadata(none) <rest>
+#locate(loc => {
+  test(query(<rest>, loc).first().location().position().y, 60pt)
+})
+
+---
+// Test relative spacing against the full height.
+// Ref: false
+#set page(height: 100pt, margin: 0pt)
+#v(20pt)
+#v(50%)
+#metadata(none) <full>
+#locate(loc => {
+  test(query(<full>, loc).first().location().position().y, 70pt)
+})
+
+---
+// Test horizontal spacing against the remaining space on the line.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#box(width: 20pt)#h(50%, remaining: true)#metadata(none) <rest>
+#locate(loc => {
+  test(query(<rest>, loc).first().location().position().x, 60pt)
+})
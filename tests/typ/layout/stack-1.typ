@@ -50,3 +50,37 @@
   align(left, [B]),
   [C],
 )
+
+---
+// Ref: false
+// Test that a bottom-to-top stack places its first child at the bottom.
+#stack(
+  dir: btt,
+  [#metadata(none) <first>],
+  [#metadata(none) <second>],
+)
+
+#locate(loc => {
+  let first = query(<first>, loc).first().location().position()
+  let second = query(<second>, loc).first().location().position()
+  test(first.y > second.y, true)
+  test(btt.axis(), "vertical")
+  test(btt.start(), bottom)
+})
+
+---
+// Ref: false
+// Test that a tall stack is split across pages.
+#set page(height: 50pt, margin: 5pt)
+#stack(
+  block(height: 30pt)[#metadata(none) <top>],
+  block(height: 30pt)[#metadata(none) <bottom>],
+)
+
+#locate(loc => {
+  let top = query(<top>, loc).first().location().position()
+  let bottom = query(<bottom>, loc).first().location().position()
+  test((top.page, bottom.page), (1, 2))
+  test(bottom.y, 5pt)
+  test(counter(page).final(loc), (2,))
+})
@@ -34,5 +34,87 @@
 #table()
 
 ---
-// Error: 14-19 expected color, none, array, or function, found string
+// Error: 14-19 expected color, pattern, none, array, or function, found string
 #table(fill: "hey")
+
+---
+// Ref: false
+// Test that right-aligned content sits flush against the inset.
+#metadata(none) <table-start>
+#table(
+  columns: (100pt, 100pt),
+  inset: 5pt,
+  align: (left, right),
+  [A], box(width: 20pt)[#metadata(none) <right>],
+  [B], table.cell(align: left, inset: 2pt, box[#metadata(none) <override>]),
+)
+
+#locate(loc => {
+  let start = query(<table-start>, loc).first().location().position()
+  let right = query(<right>, loc).first().location().position()
+  let override = query(<override>, loc).first().location().position()
+  test(right.x - start.x, 175pt)
+  test(override.x - start.x, 102pt)
+})
+
+---
+// Ref: false
+// Test styling lines individually.
+#table(columns: 2, stroke: (outer: 2pt, x: none, y: 0.5pt + blue), [A], [B], [C], [D])
+#table(columns: 2, stroke: (top: 1pt, bottom: 1pt, rest: none), [A], [B])
+#table(columns: 2, stroke: 0pt, [A], [B])
+
+---
+// Error: 16-40 unexpected key "cake", valid keys are "left", "top", "right", "bottom", "outer", "x", "y", "inner", and "rest"
+#table(stroke: (outer: 1pt, cake: 2pt))[A]
+
+---
+// Ref: false
+// Test that header rows repeat when a table breaks across pages.
+#set page(height: 100pt)
+#table(
+  columns: 2,
+  header-rows: 1,
+  [Name #metadata(none) <header>], [Value],
+  ..range(4).map(i => ([Item #i], [#i])).flatten(),
+)
+
+#locate(loc => {
+  let headers = query(<header>, loc)
+  test(headers.map(it => it.location().page()), (1, 2))
+})
+
+---
+// Ref: false
+// A table that fits onto one page doesn't repeat its header.
+#table(
+  columns: 2,
+  header-rows: 1,
+  [Name #metadata(none) <short>], [Value],
+  [A], [1],
+)
+
+#locate(loc => test(query(<short>, loc).len(), 1))
+
+---
+// Ref: false
+// An auto-height cell that breaks across pages leaves room for the repeated
+// header, so that its continuations don't overflow the page.
+#set page(height: 100pt, margin: 10pt)
+#table(
+  columns: 1,
+  header-rows: 1,
+  [Header],
+  {
+    set block(spacing: 0pt)
+    for i in range(8) {
+      block(height: 20pt, breakable: false, align(bottom)[#metadata(i) <line>])
+    }
+  },
+)
+
+#locate(loc => {
+  let lines = query(<line>, loc)
+  test(lines.len(), 8)
+  test(lines.all(it => it.location().position().y <= 90pt), true)
+})
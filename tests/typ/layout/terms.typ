@@ -50,3 +50,22 @@ Not in list
 ---
 // Error: 8 expected colon
 / Hello
+
+---
+// Ref: false
+// Test that terms are strong and descriptions are indented when they wrap.
+#set page(width: 120pt)
+#set terms(hanging-indent: 20pt)
+#show strong: it => [#metadata(it.body) <strong>#it]
+#metadata(none) <start>
+
+/ Term: First line \
+  #metadata(none) <wrapped>continued.
+/ Other: Description.
+
+#locate(loc => {
+  test(query(<strong>, loc).map(it => it.value), ([Term], [Other]))
+  let start = query(<start>, loc).first().location().position()
+  let wrapped = query(<wrapped>, loc).first().location().position()
+  test(calc.abs((wrapped.x - start.x - 20pt) / 1pt) < 0.01, true)
+})
@@ -47,3 +47,28 @@ nor #xetex!
 #box(scale(r, x: 50%, y: 200%, origin: left + top))
 #box(scale(r, x: 50%, origin: center))
 #box(scale(r, x: 50%, y: 200%, origin: right + bottom))
+
+---
+// Ref: false
+// Test that reflowed text wraps according to the swapped measure.
+#set page(width: 100pt, height: 250pt)
+#rotate(90deg, reflow: true)[
+  #box(width: 80pt)[#metadata(none) <a>]
+  #box(width: 80pt)[#metadata(none) <b>]
+]
+
+#locate(loc => {
+  let a = query(<a>, loc).first().location().position()
+  let b = query(<b>, loc).first().location().position()
+  test(calc.abs((a.x - b.x) / 1pt) < 0.01, true)
+  test(b.y > a.y, true)
+})
+
+---
+// Ref: false
+// The rotated element takes up its rotated bounding box.
+#style(styles => {
+  let size = measure(rotate(90deg, reflow: true, rect(width: 40pt, height: 10pt)), styles)
+  test(calc.abs((size.width - 10pt) / 1pt) < 0.01, true)
+  test(calc.abs((size.height - 40pt) / 1pt) < 0.01, true)
+})
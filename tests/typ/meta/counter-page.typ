@@ -8,3 +8,15 @@
 #set page(numbering: "1 / 1")
 #counter(page).update(1)
 #lorem(20)
+
+---
+// Test reading the page counter's value in the footer.
+// Ref: false
+#set page(height: 50pt, footer: locate(loc => {
+  let (number,) = counter(page).at(loc)
+  test(number, loc.page())
+  [#number]
+}))
+A
+#pagebreak()
+B
@@ -43,3 +43,12 @@ Hello
   // Error: 4-15 pagebreaks are not allowed inside of containers
   #pagebreak()
 ]
+
+---
+// Test that a plain string is typeset into a single page.
+// Ref: false
+hello #metadata(none) <end>
+#locate(loc => {
+  test(counter(page).final(loc), (1,))
+  test(query(<end>, loc).first().location().page(), 1)
+})
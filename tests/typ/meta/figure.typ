@@ -100,3 +100,15 @@ We can clearly see that @fig-cylinder and
 #show figure: set block(breakable: true)
 
 #figure(table[a][b][c][d][e], caption: [A table])
+
+---
+// Ref: false
+// Test that consecutive figures are numbered in order.
+#figure(rect(), caption: [First]) <first>
+#figure(rect(), caption: [Second]) <second>
+
+#locate(loc => {
+  let c = counter(figure.where(kind: image))
+  test(c.at(query(<first>, loc).first().location()), (1,))
+  test(c.at(query(<second>, loc).first().location()), (2,))
+})
@@ -51,3 +51,12 @@ multiline.
 =
 Not in heading
 =Nope
+
+---
+// Heading markers only count at the start of a line.
+// Ref: false
+#test([== Title].func(), heading)
+#test([== Title].level, 2)
+#test([== Title].body, [Title])
+#test([a = b].has("children"), true)
+#test([a = b].children.any(c => c.func() == heading), false)
@@ -75,3 +75,10 @@ Text <hey>
 Text <hey>
 // Error: 2-20 label occurs multiple times in the document
 #link(<hey>)[Nope.]
+
+---
+// Test link descriptions.
+// Ref: false
+#let l = link("https://typst.app", alt: "Typst's website")[Typst]
+#test(l.alt, "Typst's website")
+#test(link("https://typst.app").has("alt"), false)
@@ -35,3 +35,18 @@ Ok ...
 
 = #text(blue)[Zusammen]fassung
 #lorem(10)
+
+---
+// Ref: false
+// Test that each heading gets an entry with its page number.
+#set page(height: 200pt)
+#show outline.entry: it => [#metadata(it.page.text) <entry>#it]
+#outline()
+
+= Introduction
+#pagebreak()
+= A very long heading that wraps onto a second line in the outline
+
+#locate(loc => {
+  test(query(<entry>, loc).map(it => it.value), ("1", "2"))
+})
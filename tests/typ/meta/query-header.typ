@@ -30,3 +30,18 @@
 
 = Approach
 #lorem(60)
+
+---
+// Querying headings after layout yields them with their pages.
+// Ref: false
+#set page(height: 80pt)
+= First
+#pagebreak()
+== Second <second>
+
+#locate(loc => {
+  let headings = query(heading, loc)
+  test(headings.map(h => h.body), ([First], [Second]))
+  test(headings.map(h => h.location().page()), (1, 2))
+  test(query(<second>, loc).first().location().position().page, 2)
+})
@@ -10,3 +10,27 @@ that ever learned to mimic a human voice.
 
 ---
 Hey #box(baseline: 40%, image("/files/tiger.jpg", width: 1.5cm)) there!
+
+---
+// Inline content of different sizes shares a common baseline, so the line
+// extends below a tall box by the text's descent.
+// Ref: false
+#style(styles => {
+  let height(body) = measure(body, styles).height
+  let descent = height(text(bottom-edge: "descender")[a]) - height[a]
+  let line = height(text(bottom-edge: "descender")[#box(height: 20pt) a])
+  test(descent > 0pt, true)
+  test(calc.abs((line - 20pt - descent) / 1pt) < 0.01, true)
+})
+
+---
+// Boxes with text align on their first baselines, boxes without text on their
+// bottom edges.
+// Ref: false
+#style(styles => {
+  let height(body) = measure(body, styles).height
+  let big = box(text(20pt)[A])
+  let small = box(inset: (bottom: 5pt), text(10pt)[x])
+  test(calc.abs((height[#big#small] - height(big) - 5pt) / 1pt) < 0.01, true)
+  test(height[#box(height: 20pt)#box(height: 10pt)], 20pt)
+})
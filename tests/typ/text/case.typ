@@ -6,6 +6,7 @@
 #test(lower(memes), "are memes great?")
 #test(upper(memes), "ARE MEMES GREAT?")
 #test(upper("Ελλάδα"), "ΕΛΛΆΔΑ")
+#test(upper("abc"), "ABC")
 
 ---
 // Error: 8-9 expected string or content, found integer
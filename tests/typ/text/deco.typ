@@ -31,3 +31,14 @@ There might be #highlight[redacted] things.
 // Test stroke folding.
 #set underline(stroke: 2pt, offset: 2pt)
 #underline(text(red, [DANGER!]))
+
+---
+// Test highlighting, which follows the text across line breaks.
+// Ref: false
+#test(highlight(fill: aqua)[A].fill, aqua)
+#block(width: 40pt)[
+  This is #highlight(fill: aqua, radius: 2pt, extent: 1pt)[highlighted across lines].
+]
+#style(styles => {
+  test(measure(highlight(extent: 4pt)[Some text], styles), measure([Some text], styles))
+})
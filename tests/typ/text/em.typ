@@ -31,3 +31,15 @@ G // 5pt
 }
 
 #stack(dir: ltr, spacing: 1fr, square(size: size), square(size: 25pt))
+
+---
+// Test that mixed lengths resolve their em part against the font size.
+// Ref: false
+#set text(size: 10pt)
+#let mixed = 1em + 2pt
+#test(mixed.em, 1.0)
+#test(mixed.abs, 2pt)
+#style(styles => {
+  test(measure(box(width: mixed), styles).width, 12pt)
+  test(measure(text(size: 20pt, box(width: mixed)), styles).width, 22pt)
+})
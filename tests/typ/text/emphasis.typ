@@ -12,6 +12,15 @@ _Still #[
 
 ] emphasized._
 
+---
+// Test that the delimiters map to the functions, but not inside of words.
+// Ref: false
+#test([*hi*].func(), strong)
+#test([*hi*].body, [hi])
+#test([_hi_].func(), emph)
+#test([a*b].children.all(c => c.func() == text), true)
+#test([a*b].children.map(c => c.text).join(), "a*b")
+
 ---
 // Inside of words can still use the functions.
 P#strong[art]ly em#emph[phas]ized.
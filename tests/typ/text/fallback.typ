@@ -17,4 +17,13 @@ Aب😀🏞سمB
 01️⃣2
 
 // Tofus are rendered with the first font.
+// Warning: 1-6 no font covers the characters "ዲሞ"
+// Hint: 1-6 the characters belong to the Ethiopic script
 A🐈ዲሞB
+
+---
+// A run that breaks across lines is only reported once.
+// Warning: 2:1-2:3 no font covers the characters "ዲሞ"
+// Hint: 2:1-2:3 the characters belong to the Ethiopic script
+#set page(width: 40pt)
+ዲሞ ዲሞ ዲሞ ዲሞ ዲሞ ዲሞ
@@ -5,6 +5,19 @@
 #text(kerning: true)[Tq] \
 #text(kerning: false)[Tq]
 
+---
+// Test that kerning is applied while shaping, but not across style boundaries.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  let kerned = width(text(kerning: true)[AV])
+  let plain = width(text(kerning: false)[AV])
+  test(kerned < plain, true)
+  let split = width([A#text(weight: "bold")[V]])
+  let parts = width([A]) + width(text(weight: "bold")[V])
+  test(calc.abs((split - parts) / 1pt) < 0.001, true)
+})
+
 ---
 // Test smallcaps.
 #smallcaps[Smallcaps]
@@ -32,6 +32,8 @@ Emoji: 🐪, 🌋, 🏞
 
 // Disable font fallback beyond the user-specified list.
 // Without disabling, New Computer Modern Math would come to the rescue.
+// Warning: 2:6-2:7 no font covers the characters "𝛼𝛽"
+// Hint: 2:6-2:7 font fallback is disabled
 #set text(font: ("PT Sans", "Twitter Color Emoji"), fallback: false)
 2π = 𝛼 + 𝛽. ✅
 
@@ -58,3 +60,11 @@ Emoji: 🐪, 🌋, 🏞
 ---
 // Error: 11-31 unexpected argument: something
 #set text(something: "invalid")
+
+---
+// Test that unstyled text uses the root styles of the library.
+// Ref: false
+#style(styles => {
+  let root = measure([Hello], styles)
+  test(root, measure(text(font: "Linux Libertine", size: 10pt)[Hello], styles))
+})
@@ -49,3 +49,18 @@ It's a #emph[Tree]beard.
 #set page(width: 60pt)
 #set text(hyphenate: true)
 #h(6pt) networks, the rest.
+
+---
+// Test that a soft hyphen only adds a break opportunity and renders nothing
+// when the word fits.
+// Ref: false
+#set text(hyphenate: false)
+#style(styles => {
+  let size(body) = measure(body, styles)
+  let line = size[Separate]
+  let soft = size[Sep-?arate]
+  test(calc.abs((soft.width - line.width) / 1pt) < 0.5, true)
+  test(soft.height, line.height)
+  test(size(block(width: 25pt)[Sep-?arate]).height > line.height, true)
+  test(size(block(width: 25pt)[Separate]).height, line.height)
+})
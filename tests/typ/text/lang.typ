@@ -57,3 +57,10 @@
 ---
 // Error: 19-24 expected two letter region code (ISO 3166-1 alpha-2)
 #set text(region: "hey")
+
+---
+// Ref: false
+// Warning: 2:1-2:6 no hyphenation patterns for language "xy"
+// Hint: 2:1-2:6 text in this language will not be hyphenated
+#set text(lang: "xy", hyphenate: true)
+Hallo Welt
@@ -49,3 +49,29 @@ Second part
 ทีวีตรวจทานนอร์ทแฟรีเลคเชอร์โกลด์อัลบัมเชอร์รี่เย้วสโตร์กฤษณ์เคลมเยอบีร่าพ่อค้าบลูเบอร์รี่สหัสวรรษโฮปแคนูโยโย่จูนสตรอว์เบอร์รีซื่อบื้อเยนแบ็กโฮเป็นไงโดนัททอมสเตริโอแคนูวิทย์แดรี่โดนัทวิทย์แอปพริคอทเซอร์ไพรส์ไฮบริดกิฟท์อินเตอร์โซนเซอร์วิสเทียมทานโคโยตี้ม็อบเที่ยงคืนบุญคุณ
 
 
+
+---
+// A justified linebreak stretches the line before it to the full width.
+// Ref: false
+#set par(justify: true)
+A B#metadata(none) <stretched>#linebreak(justify: true)
+A B#metadata(none) <natural>#linebreak()
+#locate(loc => {
+  let stretched = query(<stretched>, loc).first().location().position()
+  let natural = query(<natural>, loc).first().location().position()
+  test(calc.abs((stretched.x - 110pt) / 1pt) < 0.01, true)
+  test(natural.x < 50pt, true)
+})
+
+---
+// Test that long paths wrap after slashes in breakable text.
+// Ref: false
+#let path = "/usr/local/share/fonts/truetype"
+#style(styles => {
+  let height(body) = measure(block(width: 60pt, body), styles).height
+  let line = measure([#path], styles).height
+  test(height(text(breakable: true, path)) > line, true)
+  test(height(text(breakable: true, raw(path))) > line, true)
+  test(height(text(breakable: true)[a/b]), line)
+  test(height(text(breakable: true)[v1.2.3.4.5.6.7.8.9]), line)
+})
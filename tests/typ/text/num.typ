@@ -0,0 +1,26 @@
+// Test number formatting.
+
+---
+// Ref: false
+#let out = state("out", ())
+#show regex(".+"): it => {
+  out.update(s => s + (it.text,))
+  it
+}
+
+#num(1234567.5)
+#text(lang: "de", num(1234567.5))
+#text(lang: "uk", num(1234567.5))
+#num(-1000, thousands: "'")
+#num(3.14, decimal: "·")
+#locate(loc => test(out.final(loc), (
+  "1,234,567.5",
+  "1.234.567,5",
+  "1\u{202F}234\u{202F}567,5",
+  "-1'000",
+  "3·14",
+)))
+
+---
+// Error: 6-9 expected integer or float, found string
+#num("1")
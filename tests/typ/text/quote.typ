@@ -0,0 +1,34 @@
+// Test quotes.
+// Ref: false
+
+---
+// Test that block quotes are indented and have their attribution below.
+#metadata(none) <start>
+#quote(block: true, indent: 20pt, attribution: [#metadata(none) <author>Author])[
+  #metadata(none) <body>#lorem(20)
+]
+
+#locate(loc => {
+  let start = query(<start>, loc).first().location().position()
+  let body = query(<body>, loc).first().location().position()
+  let author = query(<author>, loc).first().location().position()
+  test(body.x - start.x, 20pt)
+  test(author.y > body.y, true)
+})
+
+---
+// Test that inline quotes stay in the paragraph.
+#metadata(none) <inline-start>
+#quote(attribution: [#metadata(none) <inline-author>Author])[Quote]
+
+#locate(loc => {
+  let start = query(<inline-start>, loc).first().location().position()
+  let author = query(<inline-author>, loc).first().location().position()
+  test(author.y, start.y)
+  test(author.x > start.x, true)
+})
+
+---
+// A quote without a body is meaningless.
+// Error: 7-9 missing argument: body
+#quote()
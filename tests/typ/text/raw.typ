@@ -73,6 +73,35 @@ The keyword ```rust let```.
 #show raw: set text(font: "Roboto")
 `Roboto`
 
+---
+// Test line numbers.
+// Ref: false
+#let nums = state("nums", ())
+#show regex("\d+"): it => {
+  nums.update(s => s + (it.text,))
+  it
+}
+
+#set raw(numbering: true)
+```rust
+let a;
+let b;
+let c;
+```
+
+#raw("x\ny", block: true, start: 9)
+#locate(loc => test(nums.final(loc), ("1", "2", "3", "9", "10")))
+
+---
+// Test that tabs advance to the next tab stop.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  test(width(raw("ab\tc", tab-size: 4)), width(raw("ab  c")))
+  test(width(raw("\tc", tab-size: 4)), width(raw("    c")))
+  test(width(raw("abcd\tc", tab-size: 4)), width(raw("abcd    c")))
+})
+
 ---
 // Unterminated.
 // Error: 1-2:1 unclosed raw text
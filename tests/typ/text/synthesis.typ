@@ -0,0 +1,24 @@
+// Test synthesized bold and italic styles.
+// Ref: false
+
+---
+// Warning: 59-66 synthesizing italic style for font "PT Sans"
+#text(font: "PT Sans", style: "italic", synthesize: true)[Slanted]
+
+---
+// Warning: 58-63 synthesizing bold style for font "PT Sans"
+#text(font: "PT Sans", weight: "bold", synthesize: true)[Heavy]
+
+---
+// A real bold face exists, so nothing is synthesized.
+#text(font: "IBM Plex Sans", weight: "bold", synthesize: true)[Bold]
+
+---
+// Synthesis is opt-in.
+#text(font: "PT Sans", style: "italic")[Upright]
+
+---
+// A run that breaks across lines is only reported once.
+#set page(width: 80pt)
+// Warning: 59-66 synthesizing italic style for font "PT Sans"
+#text(font: "PT Sans", style: "italic", synthesize: true)[Slanted text across lines]
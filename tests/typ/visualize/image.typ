@@ -80,3 +80,24 @@ A #box(image("/files/tiger.jpg", height: 1cm, width: 80%)) B
 ---
 // Error: 2-83 failed to decode image
 #image.decode(read("/files/tiger.jpg", encoding: none), format: "png", width: 80%)
+
+---
+// An SVG's view box maps into the requested size.
+// Ref: false
+#let data = `<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 20 10"><rect width="20" height="10"/></svg>`.text
+#style(styles => {
+  let size = measure(image.decode(data, format: "svg", width: 40pt), styles)
+  test(size.width, 40pt)
+  test(size.height, 20pt)
+})
+
+---
+// Every fit mode fills exactly the requested box.
+// Ref: false
+#let data = `<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 20 10"><rect width="20" height="10"/></svg>`.text
+#style(styles => {
+  for fit in ("cover", "contain", "stretch") {
+    let img = image.decode(data, format: "svg", width: 20pt, height: 20pt, fit: fit)
+    test(measure(img, styles), (width: 20pt, height: 20pt))
+  }
+})
@@ -0,0 +1,22 @@
+// Test tiling patterns.
+// Ref: false
+
+---
+#let dots = pattern(width: 10pt, height: 10pt)[
+  #place(dx: 3pt, dy: 3pt, circle(radius: 2pt, fill: blue))
+]
+
+#test(type(dots), "pattern")
+#test(repr(dots), "pattern(width: 10pt, height: 10pt)")
+#rect(width: 100%, height: 25pt, fill: dots)
+#circle(radius: 15pt, stroke: (paint: dots, thickness: 4pt))
+
+---
+// The tile defaults to the size of the body.
+#let boxes = pattern(rect(width: 6pt, height: 4pt))
+#test(repr(boxes), "pattern(width: 6pt, height: 4pt)")
+#rect(width: 100%, height: 15pt, fill: boxes)
+
+---
+// Error: 2-24 pattern tile must have a non-zero size
+#pattern(width: 0pt)[A]